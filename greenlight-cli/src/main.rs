@@ -3,7 +3,11 @@ mod cli;
 use clap::Parser;
 use cli::{Args, Importance};
 use futures::future::join_all;
-use greenlight_lib::{checks::Check, config::Config, errors::GreenlightError};
+use greenlight_lib::{
+    checks::{Check, CheckContext},
+    config::Config,
+    errors::GreenlightError,
+};
 use std::collections::HashMap;
 use std::{path::PathBuf, process::ExitCode};
 use tracing::{debug, error, info, span, Level};
@@ -60,11 +64,13 @@ async fn main() -> Result<ExitCode, GreenlightError> {
         checks_to_run
     );
 
+    let ctx = CheckContext::new();
     let results = join_all(checks_to_run.into_iter().map(|(check, importance)| {
         let span = span!(Level::INFO, "check", check = ?check);
+        let ctx = &ctx;
         async move {
             info!("Running check");
-            let result = check.run().await;
+            let result = check.run(ctx).await;
             (check, importance, result)
         }
         .instrument(span)
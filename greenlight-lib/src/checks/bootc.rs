@@ -0,0 +1,207 @@
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::read_to_string;
+use tokio::process::Command;
+use tracing::{debug, error, info};
+
+/// Subset of `bootc status --format json` that Greenlight cares about.
+#[derive(Debug, Deserialize)]
+pub struct BootcHost {
+    pub status: BootcHostStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BootcHostStatus {
+    #[serde(default)]
+    pub booted: Option<BootEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BootEntry {
+    #[serde(default)]
+    pub image: Option<ImageStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageStatus {
+    pub image: ImageReference,
+    #[serde(default)]
+    pub version: Option<String>,
+    pub image_digest: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImageReference {
+    pub image: String,
+    pub transport: String,
+}
+
+impl BootcHost {
+    pub fn from_json(json: &str) -> Result<Self, GreenlightError> {
+        serde_json::from_str(json).map_err(GreenlightError::Json)
+    }
+
+    /// The image the system is currently booted into, if any.
+    pub fn booted_image(&self) -> Option<&ImageStatus> {
+        self.status.booted.as_ref()?.image.as_ref()
+    }
+}
+
+/// Key/value pairs parsed from an `os-release(5)` file.
+#[derive(Debug, Default)]
+pub struct OsRelease {
+    fields: HashMap<String, String>,
+}
+
+impl OsRelease {
+    pub fn parse(content: &str) -> Self {
+        let fields = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), unquote(value.trim())))
+            .collect();
+        OsRelease { fields }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(String::as_str)
+    }
+}
+
+fn unquote(value: &str) -> String {
+    let stripped = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value);
+    stripped.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+/// A single disagreement between `bootc status` and `/etc/os-release`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub field: &'static str,
+    pub bootc: String,
+    pub os_release: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: bootc reports '{}', /etc/os-release has '{}'",
+            self.field, self.bootc, self.os_release
+        )
+    }
+}
+
+/// Compares the booted image against os-release and returns every field that disagrees.
+///
+/// `IMAGE_VERSION` and `OSTREE_VERSION` must equal the booted image version exactly, while
+/// `VERSION_ID` only has to match its major component (e.g. `9.6` vs `9.20250429.0`).
+pub fn compare(image: &ImageStatus, os_release: &OsRelease) -> Vec<Mismatch> {
+    let version = image.version.as_deref().unwrap_or("<none>");
+    let mut mismatches = Vec::new();
+
+    for field in ["IMAGE_VERSION", "OSTREE_VERSION"] {
+        if let Some(expected) = os_release.get(field) {
+            if expected != version {
+                mismatches.push(Mismatch {
+                    field,
+                    bootc: version.to_string(),
+                    os_release: expected.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(version_id) = os_release.get("VERSION_ID") {
+        if major_version(version_id) != major_version(version) {
+            mismatches.push(Mismatch {
+                field: "VERSION_ID",
+                bootc: version.to_string(),
+                os_release: version_id.to_string(),
+            });
+        }
+    }
+
+    mismatches
+}
+
+/// Leading numeric component of a version, ignoring prefixes such as `stream` in `stream9.2024`.
+fn major_version(version: &str) -> Option<&str> {
+    let first = version.split('.').next()?;
+    let digits = first.trim_start_matches(|c: char| !c.is_ascii_digit());
+    (!digits.is_empty()).then_some(digits)
+}
+
+/// Validates `bootc status --format json` output against the contents of an os-release file.
+pub fn verify(status_json: &str, os_release: &str) -> Result<bool, GreenlightError> {
+    let host = BootcHost::from_json(status_json)?;
+    let os_release = OsRelease::parse(os_release);
+
+    let Some(image) = host.booted_image() else {
+        error!("❌ bootc status reports no booted image");
+        return Ok(false);
+    };
+    debug!(
+        "Booted image: {}:{} version={:?} digest={}",
+        image.image.transport, image.image.image, image.version, image.image_digest
+    );
+
+    if ["IMAGE_VERSION", "OSTREE_VERSION", "VERSION_ID"]
+        .iter()
+        .all(|field| os_release.get(field).is_none())
+    {
+        error!("❌ /etc/os-release has none of IMAGE_VERSION, OSTREE_VERSION or VERSION_ID");
+        return Ok(false);
+    }
+
+    let mismatches = compare(image, &os_release);
+    if mismatches.is_empty() {
+        info!(
+            "✅ Booted image '{}' ({}) matches /etc/os-release",
+            image.image.image, image.image_digest
+        );
+        return Ok(true);
+    }
+
+    error!(
+        "❌ Booted image '{}' ({}) does not match /etc/os-release:",
+        image.image.image, image.image_digest
+    );
+    for mismatch in &mismatches {
+        error!("   {}", mismatch);
+    }
+    Ok(false)
+}
+
+/// Runs `bootc status --format json` and returns its raw output.
+pub async fn bootc_status_json() -> Result<String, GreenlightError> {
+    let output = Command::new("bootc")
+        .args(["status", "--format", "json"])
+        .output()
+        .await
+        .map_err(GreenlightError::Io)?;
+
+    if !output.status.success() {
+        return Err(GreenlightError::CheckFailed(format!(
+            "bootc status exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+pub async fn bootc_status_matches_os_release() -> Result<bool, GreenlightError> {
+    let status_json = bootc_status_json().await?;
+    let os_release = read_to_string("/etc/os-release")?;
+    verify(&status_json, &os_release)
+}
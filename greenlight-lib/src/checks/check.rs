@@ -1,21 +1,220 @@
+use crate::config::Target;
 use crate::errors::GreenlightError;
+use semver::VersionReq;
 use serde::Deserialize;
 
-use crate::checks::network::Interface;
-use crate::checks::rootfs::is_rootfs_readonly;
+use crate::checks::bootc::bootc_status_matches_os_release;
+use crate::checks::cmdline::check_kernel_cmdline;
+use crate::checks::config_value::{check_config_value, ConfigFormat, ConfigScalar};
+use crate::checks::contents::{check_file_contains, ContentPattern};
+use crate::checks::context::CheckContext;
+use crate::checks::cpu::{check_cpu, CpuExpectation, CpuList};
+use crate::checks::file::{check_file, FileExpectation, FileMode, Sha256Digest};
+use crate::checks::kernel::check_kernel_version;
+use crate::checks::memory::{check_memory, HugePages};
+use crate::checks::microshift::is_microshift_installed;
+use crate::checks::modules::check_kernel_modules;
+use crate::checks::mount::check_mount;
+use crate::checks::network::{validate_interfaces, Interface, IpCidr, MacPattern};
+use crate::checks::pattern::Pattern;
+use crate::checks::process::{check_process, ProcessFilter};
+use crate::checks::rootfs::{
+    check_disk_space, check_path_writable, is_rootfs_readonly, DiskThresholds,
+};
+use crate::checks::routes::{validate_routes, Route};
+use crate::checks::selinux::{check_selinux, SelinuxMode};
+use crate::checks::sriov::check_sriov_vfs;
+use crate::checks::swap::{check_swap, parse_swaps, SwapKind, PROC_SWAPS};
+use crate::checks::sysctl::{check_sysctl, SysctlValue};
+use crate::checks::topology::Topology;
 use crate::checks::unit::{wait_for_unit, ActiveState};
+use std::collections::BTreeMap;
 use std::fs::read_to_string;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Check {
     RootfsReadonly,
     BootcStatusMatchesOsRelease,
-    MicroshiftInstalled,
+    MicroshiftInstalled {
+        /// Optional semver range the installed version must satisfy, e.g. `">=4.18, <4.19"`.
+        #[serde(default)]
+        version: Option<VersionReq>,
+    },
     Interfaces {
         interfaces: Vec<Interface>,
     },
+    NetTopology {
+        #[serde(flatten)]
+        topology: Topology,
+    },
+    Routes {
+        #[serde(default)]
+        routes: Vec<Route>,
+        /// Prefixes that must be covered by some route, including a default route.
+        #[serde(default)]
+        routable: Vec<IpCidr>,
+    },
+    SriovVfs {
+        interface: String,
+        count: u32,
+        /// Expected MAC (or OUI prefix) of each VF, indexed by VF number.
+        #[serde(default)]
+        macs: Vec<MacPattern>,
+    },
+    Mount {
+        path: PathBuf,
+        #[serde(default)]
+        fstype: Option<String>,
+        /// Options that must all be set. `ro`/`rw` and VFS flags such as `nosuid` are matched
+        /// against the per-mount options, anything else against the superblock options.
+        #[serde(default)]
+        options: Vec<String>,
+        #[serde(default)]
+        source: Option<String>,
+    },
+    PathWritable {
+        path: PathBuf,
+        writable: bool,
+    },
+    DiskSpace {
+        path: PathBuf,
+        #[serde(default)]
+        min_free_bytes: Option<u64>,
+        #[serde(default)]
+        min_free_percent: Option<u8>,
+        #[serde(default)]
+        min_free_inodes: Option<u64>,
+    },
+    KernelCmdline {
+        /// Parameters that must be set, either a bare `key` or an exact `key=value`.
+        #[serde(default)]
+        present: Vec<String>,
+        #[serde(default)]
+        absent: Vec<String>,
+        /// Expected value per key; for repeated keys the last occurrence is compared.
+        #[serde(default)]
+        values: BTreeMap<String, String>,
+    },
+    KernelVersion {
+        /// Semver range for the upstream part of the release, e.g. `">=5.14, <6"`.
+        #[serde(default)]
+        version: Option<VersionReq>,
+        /// Regex the full release string (`uname -r`) must match.
+        #[serde(default)]
+        release: Option<Pattern>,
+        /// Require the running kernel to be one shipped in `/usr/lib/modules`.
+        #[serde(default)]
+        match_deployment: bool,
+    },
+    KernelModules {
+        #[serde(default)]
+        loaded: Vec<String>,
+        #[serde(default)]
+        absent: Vec<String>,
+        /// Expected values keyed by `module.parameter`, read from `/sys/module/<m>/parameters`.
+        #[serde(default)]
+        parameters: BTreeMap<String, String>,
+    },
+    Sysctl {
+        /// Dotted name such as `net.ipv4.ip_forward`.
+        key: String,
+        /// Exact value (`1`, `"4 4 1 7"`) or numeric bound (`">=1"`).
+        expected: SysctlValue,
+    },
+    Selinux {
+        mode: SelinuxMode,
+        /// Expected `SELINUXTYPE`, e.g. `targeted`.
+        #[serde(default)]
+        policy: Option<String>,
+        /// Domains (e.g. `kubelet_t`) that must have no AVC denials since boot.
+        #[serde(default)]
+        avc_domains: Vec<String>,
+    },
+    File {
+        path: PathBuf,
+        #[serde(default = "default_true")]
+        exists: bool,
+        #[serde(default)]
+        mode: Option<FileMode>,
+        /// User name or numeric uid.
+        #[serde(default)]
+        owner: Option<String>,
+        /// Group name or numeric gid.
+        #[serde(default)]
+        group: Option<String>,
+        #[serde(default)]
+        sha256: Option<Sha256Digest>,
+        /// When false, a symlink at `path` is checked itself instead of its target.
+        #[serde(default = "default_true")]
+        follow_symlinks: bool,
+    },
+    FileContains {
+        path: PathBuf,
+        /// A whole line that must be present, or a multi-line regex when `regex = true`.
+        #[serde(flatten)]
+        pattern: ContentPattern,
+        /// Require that the pattern does *not* match.
+        #[serde(default)]
+        negate: bool,
+    },
+    ConfigValue {
+        path: PathBuf,
+        format: ConfigFormat,
+        /// Dotted key path such as `apiServer.port`; numeric segments index arrays.
+        key: String,
+        expected: ConfigScalar,
+    },
+    Process {
+        /// Process name as shown in `/proc/<pid>/comm`.
+        #[serde(default)]
+        name: Option<String>,
+        /// Regex matched against the space-joined command line.
+        #[serde(default)]
+        cmdline_regex: Option<Pattern>,
+        /// Defaults to 1, or to 0 when `max_count = 0` asserts the process is not running.
+        #[serde(default)]
+        min_count: Option<u32>,
+        #[serde(default)]
+        max_count: Option<u32>,
+        /// User name or numeric uid the process runs as.
+        #[serde(default)]
+        user: Option<String>,
+    },
+    Memory {
+        #[serde(default)]
+        min_available_mb: Option<u64>,
+        #[serde(default)]
+        max_swap_used_mb: Option<u64>,
+        /// Minimum huge page reservation, e.g. `{ size = "1G", count = 8 }`.
+        #[serde(default)]
+        hugepages: Option<HugePages>,
+    },
+    Cpu {
+        /// Exact set of online CPUs, e.g. `"0-7"`.
+        #[serde(default)]
+        online: Option<CpuList>,
+        /// Exact set of isolated CPUs; `""` asserts none are isolated.
+        #[serde(default)]
+        isolated: Option<CpuList>,
+        #[serde(default)]
+        min_count: Option<u32>,
+        /// Flags every CPU must report in `/proc/cpuinfo`.
+        #[serde(default)]
+        flags: Vec<String>,
+    },
     SwapDisabled,
+    Swap {
+        /// Kinds of swap that may be active (`zram`, `partition`, `file`); unset allows any.
+        #[serde(default)]
+        allowed: Option<Vec<SwapKind>>,
+        #[serde(default)]
+        max_total_mb: Option<u64>,
+        /// Require that no swap is active at all.
+        #[serde(default)]
+        disabled: bool,
+    },
     UnitState {
         unit: String,
         expected: ActiveState,
@@ -24,7 +223,120 @@ pub enum Check {
     },
 }
 impl Check {
-    pub async fn run(&self) -> Result<bool, GreenlightError> {
+    /// Targets this check may be configured for; `None` means every target.
+    pub fn supported_targets(&self) -> Option<&'static [Target]> {
+        match self {
+            Check::SriovVfs { .. } => Some(&[Target::DPU]),
+            _ => None,
+        }
+    }
+
+    /// Rejects settings serde cannot catch on its own, such as an invalid regex.
+    pub fn validate(&self) -> Result<(), GreenlightError> {
+        match self {
+            Check::NetTopology { topology } if topology.is_empty() => {
+                Err(GreenlightError::InvalidConfig(
+                    "net_topology check needs at least one bond, bridge or vlan".to_string(),
+                ))
+            }
+            Check::Routes { routes, routable } if routes.is_empty() && routable.is_empty() => {
+                Err(GreenlightError::InvalidConfig(
+                    "routes check needs at least one route or routable prefix".to_string(),
+                ))
+            }
+            Check::DiskSpace {
+                min_free_bytes: None,
+                min_free_percent: None,
+                min_free_inodes: None,
+                ..
+            } => Err(GreenlightError::InvalidConfig(
+                "disk_space check needs min_free_bytes, min_free_percent or min_free_inodes"
+                    .to_string(),
+            )),
+            Check::DiskSpace {
+                min_free_percent: Some(percent),
+                ..
+            } if *percent > 100 => Err(GreenlightError::InvalidConfig(format!(
+                "disk_space check min_free_percent {} exceeds 100",
+                percent
+            ))),
+            Check::KernelCmdline {
+                present,
+                absent,
+                values,
+            } if present.is_empty() && absent.is_empty() && values.is_empty() => {
+                Err(GreenlightError::InvalidConfig(
+                    "kernel_cmdline check needs present, absent or values".to_string(),
+                ))
+            }
+            Check::KernelVersion {
+                version: None,
+                release: None,
+                match_deployment: false,
+            } => Err(GreenlightError::InvalidConfig(
+                "kernel_version check needs version, release or match_deployment".to_string(),
+            )),
+            Check::KernelModules {
+                loaded,
+                absent,
+                parameters,
+            } if loaded.is_empty() && absent.is_empty() && parameters.is_empty() => {
+                Err(GreenlightError::InvalidConfig(
+                    "kernel_modules check needs loaded, absent or parameters".to_string(),
+                ))
+            }
+            Check::Memory {
+                min_available_mb: None,
+                max_swap_used_mb: None,
+                hugepages: None,
+            } => Err(GreenlightError::InvalidConfig(
+                "memory check needs min_available_mb, max_swap_used_mb or hugepages".to_string(),
+            )),
+            Check::Cpu {
+                online: None,
+                isolated: None,
+                min_count: None,
+                flags,
+            } if flags.is_empty() => Err(GreenlightError::InvalidConfig(
+                "cpu check needs online, isolated, min_count or flags".to_string(),
+            )),
+            Check::Swap {
+                allowed: None,
+                max_total_mb: None,
+                disabled: false,
+            } => Err(GreenlightError::InvalidConfig(
+                "swap check needs allowed, max_total_mb or disabled".to_string(),
+            )),
+            Check::Swap {
+                allowed,
+                max_total_mb,
+                disabled: true,
+            } if allowed.is_some() || max_total_mb.is_some() => {
+                Err(GreenlightError::InvalidConfig(
+                    "swap check with disabled = true cannot also set allowed or max_total_mb"
+                        .to_string(),
+                ))
+            }
+            Check::Process {
+                name: None,
+                cmdline_regex: None,
+                ..
+            } => Err(GreenlightError::InvalidConfig(
+                "process check needs a name or cmdline_regex".to_string(),
+            )),
+            Check::Process {
+                min_count: Some(min_count),
+                max_count: Some(max_count),
+                ..
+            } if min_count > max_count => Err(GreenlightError::InvalidConfig(format!(
+                "process check min_count {} exceeds max_count {}",
+                min_count, max_count
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn run(&self, ctx: &CheckContext) -> Result<bool, GreenlightError> {
         match self {
             Check::UnitState {
                 unit: service,
@@ -54,14 +366,234 @@ impl Check {
 
             Check::SwapDisabled => tokio::task::spawn_blocking(is_swap_off).await?,
 
-            _ => Err(GreenlightError::UnsupportedDeployment),
+            Check::Mount {
+                path,
+                fstype,
+                options,
+                source,
+            } => {
+                let (path, fstype, options, source) = (
+                    path.clone(),
+                    fstype.clone(),
+                    options.clone(),
+                    source.clone(),
+                );
+                tokio::task::spawn_blocking(move || {
+                    check_mount(&path, fstype.as_deref(), &options, source.as_deref())
+                })
+                .await?
+            }
+
+            Check::PathWritable { path, writable } => {
+                let (path, writable) = (path.clone(), *writable);
+                tokio::task::spawn_blocking(move || check_path_writable(&path, writable)).await?
+            }
+
+            Check::DiskSpace {
+                path,
+                min_free_bytes,
+                min_free_percent,
+                min_free_inodes,
+            } => {
+                let path = path.clone();
+                let thresholds = DiskThresholds {
+                    min_free_bytes: *min_free_bytes,
+                    min_free_percent: *min_free_percent,
+                    min_free_inodes: *min_free_inodes,
+                };
+                tokio::task::spawn_blocking(move || check_disk_space(&path, &thresholds)).await?
+            }
+
+            Check::KernelCmdline {
+                present,
+                absent,
+                values,
+            } => {
+                let (present, absent, values) = (present.clone(), absent.clone(), values.clone());
+                tokio::task::spawn_blocking(move || {
+                    check_kernel_cmdline(&present, &absent, &values)
+                })
+                .await?
+            }
+
+            Check::KernelVersion {
+                version,
+                release,
+                match_deployment,
+            } => {
+                let (version, release, match_deployment) =
+                    (version.clone(), release.clone(), *match_deployment);
+                tokio::task::spawn_blocking(move || {
+                    check_kernel_version(version.as_ref(), release.as_ref(), match_deployment)
+                })
+                .await?
+            }
+
+            Check::KernelModules {
+                loaded,
+                absent,
+                parameters,
+            } => {
+                let (loaded, absent, parameters) =
+                    (loaded.clone(), absent.clone(), parameters.clone());
+                tokio::task::spawn_blocking(move || {
+                    check_kernel_modules(&loaded, &absent, &parameters)
+                })
+                .await?
+            }
+
+            Check::Sysctl { key, expected } => {
+                let (key, expected) = (key.clone(), expected.clone());
+                tokio::task::spawn_blocking(move || check_sysctl(&key, &expected)).await?
+            }
+
+            Check::File {
+                path,
+                exists,
+                mode,
+                owner,
+                group,
+                sha256,
+                follow_symlinks,
+            } => {
+                let path = path.clone();
+                let expected = FileExpectation {
+                    exists: *exists,
+                    mode: *mode,
+                    owner: owner.clone(),
+                    group: group.clone(),
+                    sha256: sha256.clone(),
+                    follow_symlinks: *follow_symlinks,
+                };
+                tokio::task::spawn_blocking(move || check_file(&path, &expected)).await?
+            }
+
+            Check::FileContains {
+                path,
+                pattern,
+                negate,
+            } => {
+                let (path, pattern, negate) = (path.clone(), pattern.clone(), *negate);
+                tokio::task::spawn_blocking(move || check_file_contains(&path, &pattern, negate))
+                    .await?
+            }
+
+            Check::ConfigValue {
+                path,
+                format,
+                key,
+                expected,
+            } => {
+                let (path, format, key, expected) =
+                    (path.clone(), *format, key.clone(), expected.clone());
+                tokio::task::spawn_blocking(move || {
+                    check_config_value(&path, format, &key, &expected)
+                })
+                .await?
+            }
+
+            Check::Process {
+                name,
+                cmdline_regex,
+                min_count,
+                max_count,
+                user,
+            } => {
+                let (name, cmdline_regex, user) =
+                    (name.clone(), cmdline_regex.clone(), user.clone());
+                let max_count = *max_count;
+                let min_count = min_count.unwrap_or(if max_count == Some(0) { 0 } else { 1 });
+                tokio::task::spawn_blocking(move || {
+                    let filter = ProcessFilter {
+                        name: name.as_deref(),
+                        cmdline_regex: cmdline_regex.as_ref(),
+                        user: user.as_deref(),
+                    };
+                    check_process(&filter, min_count, max_count)
+                })
+                .await?
+            }
+
+            Check::Memory {
+                min_available_mb,
+                max_swap_used_mb,
+                hugepages,
+            } => {
+                let (min_available_mb, max_swap_used_mb, hugepages) =
+                    (*min_available_mb, *max_swap_used_mb, hugepages.clone());
+                tokio::task::spawn_blocking(move || {
+                    check_memory(min_available_mb, max_swap_used_mb, hugepages.as_ref())
+                })
+                .await?
+            }
+
+            Check::Cpu {
+                online,
+                isolated,
+                min_count,
+                flags,
+            } => {
+                let (online, isolated, min_count, flags) =
+                    (online.clone(), isolated.clone(), *min_count, flags.clone());
+                tokio::task::spawn_blocking(move || {
+                    check_cpu(&CpuExpectation {
+                        online: online.as_ref(),
+                        isolated: isolated.as_ref(),
+                        min_count,
+                        flags: &flags,
+                    })
+                })
+                .await?
+            }
+
+            Check::Swap {
+                allowed,
+                max_total_mb,
+                disabled,
+            } => {
+                let (allowed, max_total_mb, disabled) = (allowed.clone(), *max_total_mb, *disabled);
+                tokio::task::spawn_blocking(move || {
+                    check_swap(allowed.as_deref(), max_total_mb, disabled)
+                })
+                .await?
+            }
+
+            Check::Selinux {
+                mode,
+                policy,
+                avc_domains,
+            } => check_selinux(*mode, policy.as_deref(), avc_domains).await,
+
+            Check::BootcStatusMatchesOsRelease => bootc_status_matches_os_release().await,
+
+            Check::MicroshiftInstalled { version } => {
+                is_microshift_installed(version.as_ref()).await
+            }
+
+            Check::Interfaces { interfaces } => {
+                Ok(validate_interfaces(ctx.links().await?, interfaces))
+            }
+
+            Check::NetTopology { topology } => Ok(topology.validate(ctx.links().await?)),
+
+            Check::Routes { routes, routable } => {
+                validate_routes(ctx.netlink().await?, ctx.links().await?, routes, routable).await
+            }
+
+            Check::SriovVfs {
+                interface,
+                count,
+                macs,
+            } => check_sriov_vfs(ctx.netlink().await?, interface, *count, macs).await,
         }
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
 pub fn is_swap_off() -> Result<bool, GreenlightError> {
-    let content = read_to_string("/proc/swaps")?;
-    let mut lines = content.lines();
-    lines.next();
-    Ok(lines.next().is_none())
+    let content = read_to_string(PROC_SWAPS)?;
+    Ok(parse_swaps(&content)?.is_empty())
 }
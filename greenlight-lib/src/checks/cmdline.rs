@@ -0,0 +1,222 @@
+use crate::errors::GreenlightError;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use tracing::{debug, error, info};
+
+/// One `key` or `key=value` parameter from the kernel command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelParam {
+    pub key: String,
+    pub value: Option<String>,
+}
+
+/// Splits a command line the way the kernel does: on whitespace outside double quotes,
+/// with the quotes themselves removed (`key="a b"` and `"key=a b"` are equivalent).
+pub fn parse_cmdline(cmdline: &str) -> Vec<KernelParam> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_token = false;
+    for c in cmdline.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_token = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_token {
+                    tokens.push(std::mem::take(&mut current));
+                    has_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_token = true;
+            }
+        }
+    }
+    if has_token {
+        tokens.push(current);
+    }
+
+    tokens
+        .into_iter()
+        .map(|token| match token.split_once('=') {
+            Some((key, value)) => KernelParam {
+                key: key.to_string(),
+                value: Some(value.to_string()),
+            },
+            None => KernelParam {
+                key: token,
+                value: None,
+            },
+        })
+        .collect()
+}
+
+/// The kernel treats `-` and `_` in parameter names as the same character.
+fn same_key(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.chars()
+            .zip(b.chars())
+            .all(|(x, y)| x == y || (matches!(x, '-' | '_') && matches!(y, '-' | '_')))
+}
+
+/// Returns true if `expected` (`key` or `key=value`) appears among `params`.
+fn contains(params: &[KernelParam], expected: &str) -> bool {
+    match expected.split_once('=') {
+        Some((key, value)) => params
+            .iter()
+            .any(|p| same_key(&p.key, key) && p.value.as_deref() == Some(value)),
+        None => params.iter().any(|p| same_key(&p.key, expected)),
+    }
+}
+
+/// Validates parsed kernel parameters.
+///
+/// `present` and `absent` entries are either a bare key, matching any value, or an
+/// exact `key=value` pair. For `values`, the last occurrence of a repeated key wins,
+/// as it does for most kernel parameters.
+pub fn check_cmdline(
+    params: &[KernelParam],
+    present: &[String],
+    absent: &[String],
+    values: &BTreeMap<String, String>,
+) -> bool {
+    let mut ok = true;
+
+    for expected in present {
+        if !contains(params, expected) {
+            error!("❌ Kernel parameter '{}' is missing", expected);
+            ok = false;
+        }
+    }
+
+    for unexpected in absent {
+        if contains(params, unexpected) {
+            error!("❌ Kernel parameter '{}' is set", unexpected);
+            ok = false;
+        }
+    }
+
+    for (key, expected) in values {
+        let found: Vec<Option<&str>> = params
+            .iter()
+            .filter(|p| same_key(&p.key, key))
+            .map(|p| p.value.as_deref())
+            .collect();
+        match found.last() {
+            Some(Some(value)) if value == expected => {
+                debug!("✅ Kernel parameter {}={}", key, value);
+            }
+            Some(_) => {
+                let found: Vec<&str> = found.iter().map(|v| v.unwrap_or("<no value>")).collect();
+                error!(
+                    "❌ Kernel parameter '{}' mismatch: expected {}, got {}",
+                    key,
+                    expected,
+                    found.join(", ")
+                );
+                ok = false;
+            }
+            None => {
+                error!(
+                    "❌ Kernel parameter '{}' is missing, expected {}",
+                    key, expected
+                );
+                ok = false;
+            }
+        }
+    }
+
+    if ok {
+        info!("✅ Kernel command line matches expectations");
+    }
+    ok
+}
+
+pub fn check_kernel_cmdline(
+    present: &[String],
+    absent: &[String],
+    values: &BTreeMap<String, String>,
+) -> Result<bool, GreenlightError> {
+    let cmdline = read_to_string("/proc/cmdline")?;
+    debug!("Kernel command line: {}", cmdline.trim());
+    Ok(check_cmdline(
+        &parse_cmdline(&cmdline),
+        present,
+        absent,
+        values,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CMDLINE: &str = "BOOT_IMAGE=(hd0,gpt3)/boot/vmlinuz root=UUID=1234 rw \
+        console=tty0 console=ttyS0,115200n8 isolcpus=1 isolcpus=2-7 \
+        dyndbg=\"file drivers/net/* +p\" \"systemd.log_level=debug\" nohz_full=2-7 quiet\n";
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_cmdline() {
+        let params = parse_cmdline(CMDLINE);
+        assert_eq!(params.len(), 11);
+        assert_eq!(params[1].key, "root");
+        assert_eq!(params[1].value.as_deref(), Some("UUID=1234"));
+        assert_eq!(params[2].value, None);
+        assert_eq!(params[7].key, "dyndbg");
+        assert_eq!(params[7].value.as_deref(), Some("file drivers/net/* +p"));
+        assert_eq!(params[8].key, "systemd.log_level");
+        assert_eq!(params[8].value.as_deref(), Some("debug"));
+        assert_eq!(params[10].key, "quiet");
+    }
+
+    #[test]
+    fn test_present_and_absent() {
+        let params = parse_cmdline(CMDLINE);
+        let none = BTreeMap::new();
+        assert!(check_cmdline(
+            &params,
+            &strings(&["quiet", "console=ttyS0,115200n8", "nohz-full"]),
+            &strings(&["nosmt", "console=ttyS1"]),
+            &none
+        ));
+        assert!(!check_cmdline(&params, &strings(&["nosmt"]), &[], &none));
+        assert!(!check_cmdline(&params, &[], &strings(&["rw"]), &none));
+    }
+
+    #[test]
+    fn test_values() {
+        let params = parse_cmdline(CMDLINE);
+        let values = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert!(check_cmdline(
+            &params,
+            &[],
+            &[],
+            &values(&[("isolcpus", "2-7"), ("root", "UUID=1234")])
+        ));
+        assert!(!check_cmdline(
+            &params,
+            &[],
+            &[],
+            &values(&[("isolcpus", "1")])
+        ));
+        assert!(!check_cmdline(&params, &[], &[], &values(&[("rw", "1")])));
+        assert!(!check_cmdline(
+            &params,
+            &[],
+            &[],
+            &values(&[("nosmt", "force")])
+        ));
+    }
+}
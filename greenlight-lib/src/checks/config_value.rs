@@ -0,0 +1,239 @@
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+    Yaml,
+    Ini,
+}
+
+/// Expected scalar at a key path. Floats are left out so checks stay `Eq + Hash`;
+/// compare them as strings instead.
+#[derive(Debug, Clone, PartialEq, Deserialize, Eq, Hash)]
+#[serde(untagged)]
+pub enum ConfigScalar {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+}
+
+impl fmt::Display for ConfigScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigScalar::Bool(b) => write!(f, "{}", b),
+            ConfigScalar::Integer(n) => write!(f, "{}", n),
+            ConfigScalar::String(s) => write!(f, "{:?}", s),
+        }
+    }
+}
+
+impl ConfigScalar {
+    /// Typed comparison; with `untyped` (INI) every value is a string and `true`/`42`
+    /// are compared by their text.
+    pub fn matches(&self, actual: &Value, untyped: bool) -> bool {
+        match (self, actual) {
+            (ConfigScalar::Bool(expected), Value::Bool(actual)) => expected == actual,
+            (ConfigScalar::Integer(expected), Value::Number(actual)) => {
+                actual.as_i64() == Some(*expected)
+            }
+            (ConfigScalar::String(expected), Value::String(actual)) => expected == actual,
+            (ConfigScalar::Bool(expected), Value::String(actual)) if untyped => {
+                actual == &expected.to_string()
+            }
+            (ConfigScalar::Integer(expected), Value::String(actual)) if untyped => {
+                actual == &expected.to_string()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Parses a minimal INI dialect: `[section]` headers, `key = value` or `key: value`
+/// pairs, `;`/`#` comments, and optional quotes around values. Keys before the first
+/// section live at the top level.
+pub fn parse_ini(content: &str) -> Value {
+    let mut root = Map::new();
+    let mut section: Option<String> = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = name.trim().to_string();
+            root.entry(name.clone())
+                .or_insert_with(|| Value::Object(Map::new()));
+            section = Some(name);
+            continue;
+        }
+        let Some((key, value)) = line.split_once(['=', ':']) else {
+            continue;
+        };
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        let table = match &section {
+            Some(name) => root.get_mut(name).and_then(Value::as_object_mut),
+            None => Some(&mut root),
+        };
+        if let Some(table) = table {
+            table.insert(key.trim().to_string(), Value::String(value.to_string()));
+        }
+    }
+    Value::Object(root)
+}
+
+/// Parses `content` into a JSON value regardless of its original format.
+pub fn parse_document(content: &str, format: ConfigFormat) -> Result<Value, GreenlightError> {
+    let invalid =
+        |e: &dyn fmt::Display| GreenlightError::CheckFailed(format!("Invalid {:?}: {}", format, e));
+    match format {
+        ConfigFormat::Toml => {
+            let value: toml::Value = toml::from_str(content).map_err(|e| invalid(&e))?;
+            Ok(serde_json::to_value(value)?)
+        }
+        ConfigFormat::Json => Ok(serde_json::from_str(content)?),
+        ConfigFormat::Yaml => serde_norway::from_str(content).map_err(|e| invalid(&e)),
+        ConfigFormat::Ini => Ok(parse_ini(content)),
+    }
+}
+
+/// Looks up a dotted key path; numeric segments index into arrays.
+pub fn lookup<'a>(document: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.')
+        .try_fold(document, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+pub fn check_config_value(
+    path: &Path,
+    format: ConfigFormat,
+    key: &str,
+    expected: &ConfigScalar,
+) -> Result<bool, GreenlightError> {
+    if !path.is_file() {
+        error!("❌ '{}' does not exist", path.display());
+        return Ok(false);
+    }
+
+    let document = parse_document(&read_to_string(path)?, format)?;
+    let Some(actual) = lookup(&document, key) else {
+        error!("❌ '{}' has no key '{}'", path.display(), key);
+        return Ok(false);
+    };
+    debug!("'{}' {} = {}", path.display(), key, actual);
+
+    if expected.matches(actual, format == ConfigFormat::Ini) {
+        info!("✅ '{}' {} = {}", path.display(), key, actual);
+        Ok(true)
+    } else {
+        error!(
+            "❌ '{}' {} mismatch: expected {}, got {}",
+            path.display(),
+            key,
+            expected,
+            actual
+        );
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    const YAML: &str = "\
+dns:
+  baseDomain: example.com
+network:
+  clusterNetwork:
+    - 10.42.0.0/16
+apiServer:
+  port: 6443
+debugging:
+  enabled: false
+";
+
+    const INI: &str = "\
+; top-level
+mode = edge
+
+[server]
+port = 8080
+tls: true
+name = \"edge node\"
+";
+
+    #[test]
+    fn test_lookup_across_formats() {
+        let yaml = parse_document(YAML, ConfigFormat::Yaml).unwrap();
+        assert_eq!(
+            lookup(&yaml, "dns.baseDomain"),
+            Some(&Value::from("example.com"))
+        );
+        assert_eq!(
+            lookup(&yaml, "network.clusterNetwork.0"),
+            Some(&Value::from("10.42.0.0/16"))
+        );
+        assert_eq!(lookup(&yaml, "network.serviceNetwork"), None);
+
+        let toml = parse_document("[server]\nport = 8080\n", ConfigFormat::Toml).unwrap();
+        assert_eq!(lookup(&toml, "server.port"), Some(&Value::from(8080)));
+
+        let json = parse_document(r#"{"a": {"b": [true]}}"#, ConfigFormat::Json).unwrap();
+        assert_eq!(lookup(&json, "a.b.0"), Some(&Value::from(true)));
+
+        let ini = parse_document(INI, ConfigFormat::Ini).unwrap();
+        assert_eq!(lookup(&ini, "mode"), Some(&Value::from("edge")));
+        assert_eq!(lookup(&ini, "server.tls"), Some(&Value::from("true")));
+        assert_eq!(lookup(&ini, "server.name"), Some(&Value::from("edge node")));
+    }
+
+    #[test]
+    fn test_scalar_matches() {
+        assert!(ConfigScalar::Integer(6443).matches(&Value::from(6443), false));
+        assert!(!ConfigScalar::Integer(6443).matches(&Value::from("6443"), false));
+        assert!(ConfigScalar::Integer(8080).matches(&Value::from("8080"), true));
+        assert!(ConfigScalar::Bool(true).matches(&Value::from("true"), true));
+        assert!(!ConfigScalar::String("x".into()).matches(&Value::Null, false));
+    }
+
+    #[test]
+    fn test_check_config_value() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.yaml");
+        write(&path, YAML).unwrap();
+
+        let check = |key: &str, expected: ConfigScalar| {
+            check_config_value(&path, ConfigFormat::Yaml, key, &expected).unwrap()
+        };
+        assert!(check("apiServer.port", ConfigScalar::Integer(6443)));
+        assert!(check("debugging.enabled", ConfigScalar::Bool(false)));
+        assert!(!check(
+            "dns.baseDomain",
+            ConfigScalar::String("example.org".into())
+        ));
+        assert!(!check("dns.missing", ConfigScalar::Bool(true)));
+
+        write(&path, "not: [valid").unwrap();
+        assert!(
+            check_config_value(&path, ConfigFormat::Yaml, "not", &ConfigScalar::Bool(true))
+                .is_err()
+        );
+    }
+}
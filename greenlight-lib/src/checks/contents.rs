@@ -0,0 +1,167 @@
+use crate::checks::pattern::Pattern;
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+/// What a `file_contains` check looks for, written as `pattern` plus an optional `regex = true`.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "RawContentPattern")]
+pub enum ContentPattern {
+    /// A whole line, compared with surrounding whitespace trimmed.
+    Line(String),
+    /// A regex where `^` and `$` anchor to line boundaries.
+    Regex(Pattern),
+}
+
+#[derive(Deserialize)]
+struct RawContentPattern {
+    pattern: String,
+    #[serde(default)]
+    regex: bool,
+}
+
+impl TryFrom<RawContentPattern> for ContentPattern {
+    type Error = String;
+
+    fn try_from(raw: RawContentPattern) -> Result<Self, Self::Error> {
+        if raw.regex {
+            raw.pattern.parse().map(ContentPattern::Regex)
+        } else {
+            Ok(ContentPattern::Line(raw.pattern))
+        }
+    }
+}
+
+impl fmt::Display for ContentPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentPattern::Line(line) => write!(f, "{:?}", line),
+            ContentPattern::Regex(pattern) => write!(f, "/{}/", pattern),
+        }
+    }
+}
+
+/// Returns the 1-based line number where `pattern` first matches in `content`.
+pub fn find_match(content: &str, pattern: &ContentPattern) -> Option<usize> {
+    match pattern {
+        ContentPattern::Line(expected) => content
+            .lines()
+            .position(|line| line.trim() == expected.trim())
+            .map(|index| index + 1),
+        ContentPattern::Regex(pattern) => pattern
+            .multi_line_regex()
+            .find(content)
+            .map(|m| content[..m.start()].matches('\n').count() + 1),
+    }
+}
+
+pub fn check_file_contains(
+    path: &Path,
+    pattern: &ContentPattern,
+    negate: bool,
+) -> Result<bool, GreenlightError> {
+    if !path.is_file() {
+        error!("❌ '{}' does not exist", path.display());
+        return Ok(false);
+    }
+
+    let content = read_to_string(path)?;
+    let found = find_match(&content, pattern);
+    debug!("'{}' match for {}: {:?}", path.display(), pattern, found);
+
+    match (found, negate) {
+        (Some(line), false) => {
+            info!(
+                "✅ '{}' contains {} (line {})",
+                path.display(),
+                pattern,
+                line
+            );
+            Ok(true)
+        }
+        (None, true) => {
+            info!("✅ '{}' does not contain {}", path.display(), pattern);
+            Ok(true)
+        }
+        (None, false) => {
+            error!("❌ '{}' does not contain {}", path.display(), pattern);
+            Ok(false)
+        }
+        (Some(line), true) => {
+            error!(
+                "❌ '{}' contains {} at line {}",
+                path.display(),
+                pattern,
+                line
+            );
+            Ok(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    const SSHD_CONFIG: &str = "\
+Include /etc/ssh/sshd_config.d/*.conf
+#PermitRootLogin prohibit-password
+PermitRootLogin no
+PasswordAuthentication no
+";
+
+    fn line(s: &str) -> ContentPattern {
+        ContentPattern::Line(s.to_string())
+    }
+
+    fn regex(s: &str) -> ContentPattern {
+        ContentPattern::Regex(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_find_match() {
+        assert_eq!(
+            find_match(SSHD_CONFIG, &line("PermitRootLogin no")),
+            Some(3)
+        );
+        assert_eq!(
+            find_match(SSHD_CONFIG, &line("  PasswordAuthentication no ")),
+            Some(4)
+        );
+        assert_eq!(find_match(SSHD_CONFIG, &line("PermitRootLogin")), None);
+        assert_eq!(
+            find_match(SSHD_CONFIG, &regex(r"^PermitRootLogin\s+no$")),
+            Some(3)
+        );
+        assert_eq!(
+            find_match(SSHD_CONFIG, &regex(r"^PermitRootLogin\s+yes")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_commented_line_does_not_match() {
+        let commented = "#PermitRootLogin no\nPermitRootLogin noway\n";
+        assert_eq!(find_match(commented, &line("PermitRootLogin no")), None);
+    }
+
+    #[test]
+    fn test_check_file_contains() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("sshd_config");
+        write(&path, SSHD_CONFIG).unwrap();
+
+        assert!(check_file_contains(&path, &line("PermitRootLogin no"), false).unwrap());
+        assert!(!check_file_contains(&path, &line("PermitRootLogin no"), true).unwrap());
+        assert!(check_file_contains(&path, &regex(r"^PermitRootLogin\s+yes"), true).unwrap());
+        assert!(
+            !check_file_contains(&path, &regex(r"^PasswordAuthentication\s+yes"), false).unwrap()
+        );
+        assert!(!check_file_contains(&dir.path().join("missing"), &line("x"), true).unwrap());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::checks::network::{LinkSnapshot, Netlink};
+use crate::errors::GreenlightError;
+use tokio::sync::OnceCell;
+
+/// Resources shared by all checks in a single Greenlight run.
+///
+/// The runner creates one context and passes it to every `Check::run`, so expensive
+/// handles such as the netlink connection are opened at most once and only when needed,
+/// and every network check validates against the same link and address dump.
+#[derive(Debug, Default)]
+pub struct CheckContext {
+    netlink: OnceCell<Netlink>,
+    links: OnceCell<LinkSnapshot>,
+}
+
+impl CheckContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared netlink connection, opening it on first use.
+    pub async fn netlink(&self) -> Result<&Netlink, GreenlightError> {
+        self.netlink
+            .get_or_try_init(|| async { Netlink::connect() })
+            .await
+    }
+
+    /// Returns the shared link and address snapshot, dumping it on first use.
+    pub async fn links(&self) -> Result<&LinkSnapshot, GreenlightError> {
+        self.links
+            .get_or_try_init(|| async { self.netlink().await?.snapshot().await })
+            .await
+    }
+}
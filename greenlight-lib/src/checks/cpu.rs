@@ -0,0 +1,261 @@
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::{fmt, str::FromStr};
+use tracing::{debug, error, info};
+
+const SYS_CPU: &str = "/sys/devices/system/cpu";
+const PROC_CPUINFO: &str = "/proc/cpuinfo";
+/// Largest `NR_CPUS` the kernel can be built with; bounds what a typo'd range can allocate.
+const MAX_CPUS: u32 = 8192;
+
+/// A set of CPUs in the kernel's cpulist format, e.g. `0-3,6,8-11`; empty means none.
+#[derive(Clone, Default, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
+pub struct CpuList(pub BTreeSet<u32>);
+
+impl FromStr for CpuList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid CPU list '{}', expected e.g. 0-3,6 with CPU ids below {}",
+                s, MAX_CPUS
+            )
+        };
+        let mut cpus = BTreeSet::new();
+        for range in s.trim().split(',').filter(|r| !r.is_empty()) {
+            let (start, end) = match range.split_once('-') {
+                Some((start, end)) => (start, end),
+                None => (range, range),
+            };
+            let start: u32 = start.trim().parse().map_err(|_| invalid())?;
+            let end: u32 = end.trim().parse().map_err(|_| invalid())?;
+            if start > end || end >= MAX_CPUS {
+                return Err(invalid());
+            }
+            cpus.extend(start..=end);
+        }
+        Ok(CpuList(cpus))
+    }
+}
+
+impl TryFrom<String> for CpuList {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for CpuList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ranges: Vec<(u32, u32)> = Vec::new();
+        for &cpu in &self.0 {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == cpu => *end = cpu,
+                _ => ranges.push((cpu, cpu)),
+            }
+        }
+        let ranges: Vec<String> = ranges
+            .iter()
+            .map(|&(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}-{}", start, end)
+                }
+            })
+            .collect();
+        if ranges.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&ranges.join(","))
+        }
+    }
+}
+
+impl fmt::Debug for CpuList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CpuList({})", self)
+    }
+}
+
+/// Flags present on every CPU in `/proc/cpuinfo` (`flags` on x86, `Features` on arm64).
+pub fn common_cpu_flags(cpuinfo: &str) -> BTreeSet<String> {
+    cpuinfo
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            matches!(key.trim(), "flags" | "Features")
+                .then(|| value.split_whitespace().map(str::to_string).collect())
+        })
+        .reduce(|common: BTreeSet<String>, flags| &common & &flags)
+        .unwrap_or_default()
+}
+
+/// Expected CPU state; unset fields are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CpuExpectation<'a> {
+    pub online: Option<&'a CpuList>,
+    pub isolated: Option<&'a CpuList>,
+    pub min_count: Option<u32>,
+    pub flags: &'a [String],
+}
+
+fn read_cpu_list(path: &Path) -> Result<CpuList, GreenlightError> {
+    // `isolated` is missing on kernels built without CPU isolation support.
+    if !path.is_file() {
+        return Ok(CpuList::default());
+    }
+    read_to_string(path)?
+        .parse()
+        .map_err(|e| GreenlightError::CheckFailed(format!("{}: {}", path.display(), e)))
+}
+
+/// Validates CPUs against a `/sys/devices/system/cpu` root and `/proc/cpuinfo` content.
+pub fn check_cpu_in(
+    sys_cpu: &Path,
+    cpuinfo: &str,
+    expected: &CpuExpectation,
+) -> Result<bool, GreenlightError> {
+    let online = read_cpu_list(&sys_cpu.join("online"))?;
+    let isolated = read_cpu_list(&sys_cpu.join("isolated"))?;
+    debug!("CPUs online: {}, isolated: {}", online, isolated);
+
+    let mut ok = true;
+    if let Some(expected) = expected.online {
+        if online != *expected {
+            error!(
+                "❌ Online CPUs mismatch: expected {}, got {}",
+                expected, online
+            );
+            ok = false;
+        }
+    }
+    if let Some(expected) = expected.isolated {
+        if isolated != *expected {
+            error!(
+                "❌ Isolated CPUs mismatch: expected {}, got {}",
+                expected, isolated
+            );
+            ok = false;
+        }
+    }
+    if let Some(min) = expected.min_count {
+        if (online.0.len() as u32) < min {
+            error!(
+                "❌ {} CPUs online, expected at least {}",
+                online.0.len(),
+                min
+            );
+            ok = false;
+        }
+    }
+
+    if !expected.flags.is_empty() {
+        let flags = common_cpu_flags(cpuinfo);
+        for flag in expected.flags {
+            if !flags.contains(flag) {
+                error!("❌ CPU flag '{}' is not available on all CPUs", flag);
+                ok = false;
+            }
+        }
+    }
+
+    if ok {
+        info!(
+            "✅ CPUs match expectations (online {}, isolated {})",
+            online, isolated
+        );
+    }
+    Ok(ok)
+}
+
+pub fn check_cpu(expected: &CpuExpectation) -> Result<bool, GreenlightError> {
+    let cpuinfo = if expected.flags.is_empty() {
+        String::new()
+    } else {
+        read_to_string(PROC_CPUINFO)?
+    };
+    check_cpu_in(Path::new(SYS_CPU), &cpuinfo, expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    const CPUINFO: &str = "\
+processor\t: 0
+flags\t\t: fpu sse2 avx avx2 aes
+processor\t: 1
+flags\t\t: fpu sse2 avx aes
+";
+
+    fn cpus(s: &str) -> CpuList {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_cpu_list() {
+        assert_eq!(cpus("0-3,6,8-9").0, BTreeSet::from([0, 1, 2, 3, 6, 8, 9]));
+        assert_eq!(cpus("0-3,6,8-9").to_string(), "0-3,6,8-9");
+        assert_eq!(cpus("\n").0, BTreeSet::new());
+        assert_eq!(cpus("").to_string(), "none");
+        assert!("3-1".parse::<CpuList>().is_err());
+        assert!("a-b".parse::<CpuList>().is_err());
+        assert!("0-4294967295".parse::<CpuList>().is_err());
+        assert!("8191".parse::<CpuList>().is_ok());
+        assert_eq!(format!("{:?}", cpus("0-7")), "CpuList(0-7)");
+    }
+
+    #[test]
+    fn test_common_cpu_flags() {
+        let flags = common_cpu_flags(CPUINFO);
+        assert!(flags.contains("avx"));
+        assert!(!flags.contains("avx2"));
+        assert!(common_cpu_flags("Features\t: fp asimd aes\n").contains("asimd"));
+    }
+
+    #[test]
+    fn test_check_cpu_in() {
+        let root = TempDir::new().unwrap();
+        write(root.path().join("online"), "0-7\n").unwrap();
+        write(root.path().join("isolated"), "2-7\n").unwrap();
+        let online = cpus("0-7");
+        let isolated = cpus("2-7");
+        let flags = vec!["avx".to_string(), "aes".to_string()];
+
+        let ok = CpuExpectation {
+            online: Some(&online),
+            isolated: Some(&isolated),
+            min_count: Some(8),
+            flags: &flags,
+        };
+        assert!(check_cpu_in(root.path(), CPUINFO, &ok).unwrap());
+
+        let not_isolated = cpus("");
+        let cases = [
+            CpuExpectation {
+                isolated: Some(&not_isolated),
+                ..Default::default()
+            },
+            CpuExpectation {
+                min_count: Some(16),
+                ..Default::default()
+            },
+            CpuExpectation {
+                flags: &["avx2".to_string()],
+                ..Default::default()
+            },
+        ];
+        for expected in &cases {
+            assert!(!check_cpu_in(root.path(), CPUINFO, expected).unwrap());
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use crate::errors::GreenlightError;
+use nix::unistd::{Gid, Group, Uid, User};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs::{metadata, symlink_metadata, File, Metadata};
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+use std::{fmt, io, str::FromStr};
+use tracing::{debug, error, info};
+
+/// Permission bits written in octal, e.g. `"0644"` or `"4755"`.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
+pub struct FileMode(pub u32);
+
+impl FromStr for FileMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match u32::from_str_radix(s.trim_start_matches("0o"), 8) {
+            Ok(mode) if mode <= 0o7777 => Ok(FileMode(mode)),
+            _ => Err(format!(
+                "Invalid file mode '{}', expected octal like 0644",
+                s
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for FileMode {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for FileMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04o}", self.0)
+    }
+}
+
+/// Lowercase hex SHA-256 digest.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
+pub struct Sha256Digest(String);
+
+impl FromStr for Sha256Digest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit()) {
+            Ok(Sha256Digest(s.to_lowercase()))
+        } else {
+            Err(format!("Invalid SHA-256 digest '{}'", s))
+        }
+    }
+}
+
+impl TryFrom<String> for Sha256Digest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Sha256Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Expected state of a file; unset fields are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileExpectation {
+    pub exists: bool,
+    pub mode: Option<FileMode>,
+    /// User name or numeric uid.
+    pub owner: Option<String>,
+    /// Group name or numeric gid.
+    pub group: Option<String>,
+    pub sha256: Option<Sha256Digest>,
+    /// Check the symlink target rather than the link itself.
+    pub follow_symlinks: bool,
+}
+
+pub fn sha256_file(path: &Path) -> Result<String, GreenlightError> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+pub(crate) fn user_name(uid: u32) -> String {
+    match User::from_uid(Uid::from_raw(uid)) {
+        Ok(Some(user)) => user.name,
+        _ => uid.to_string(),
+    }
+}
+
+fn group_name(gid: u32) -> String {
+    match Group::from_gid(Gid::from_raw(gid)) {
+        Ok(Some(group)) => group.name,
+        _ => gid.to_string(),
+    }
+}
+
+/// Matches `expected` (a name or a numeric id) against `id`, whose name is `name`.
+pub(crate) fn id_matches(expected: &str, id: u32, name: &str) -> bool {
+    match expected.parse::<u32>() {
+        Ok(expected_id) => expected_id == id,
+        Err(_) => expected == name,
+    }
+}
+
+fn check_metadata(path: &Path, meta: &Metadata, expected: &FileExpectation) -> Vec<String> {
+    let mut mismatches = Vec::new();
+
+    if let Some(mode) = expected.mode {
+        let actual = FileMode(meta.mode() & 0o7777);
+        if actual != mode {
+            mismatches.push(format!("mode: expected {}, got {}", mode, actual));
+        }
+    }
+
+    if let Some(owner) = &expected.owner {
+        let name = user_name(meta.uid());
+        if !id_matches(owner, meta.uid(), &name) {
+            mismatches.push(format!("owner: expected {}, got {}", owner, name));
+        }
+    }
+
+    if let Some(group) = &expected.group {
+        let name = group_name(meta.gid());
+        if !id_matches(group, meta.gid(), &name) {
+            mismatches.push(format!("group: expected {}, got {}", group, name));
+        }
+    }
+
+    if let Some(digest) = &expected.sha256 {
+        if meta.file_type().is_symlink() {
+            mismatches.push("sha256: path is a symlink and follow_symlinks is false".to_string());
+        } else if !meta.is_file() {
+            mismatches.push("sha256: path is not a regular file".to_string());
+        } else {
+            match sha256_file(path) {
+                Ok(actual) if actual == digest.0 => {}
+                Ok(actual) => {
+                    mismatches.push(format!("sha256: expected {}, got {}", digest, actual))
+                }
+                Err(e) => mismatches.push(format!("sha256: {}", e)),
+            }
+        }
+    }
+
+    mismatches
+}
+
+pub fn check_file(path: &Path, expected: &FileExpectation) -> Result<bool, GreenlightError> {
+    let lookup = if expected.follow_symlinks {
+        metadata(path)
+    } else {
+        symlink_metadata(path)
+    };
+
+    let meta = match lookup {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if expected.exists {
+                error!("❌ '{}' does not exist", path.display());
+                return Ok(false);
+            }
+            info!("✅ '{}' does not exist", path.display());
+            return Ok(true);
+        }
+        Err(e) => return Err(GreenlightError::Io(e)),
+    };
+
+    if !expected.exists {
+        error!("❌ '{}' exists but should not", path.display());
+        return Ok(false);
+    }
+    debug!("'{}' metadata: {:?}", path.display(), meta);
+
+    let mismatches = check_metadata(path, &meta, expected);
+    for mismatch in &mismatches {
+        error!("❌ '{}' {}", path.display(), mismatch);
+    }
+
+    if mismatches.is_empty() {
+        info!("✅ '{}' matches expectations", path.display());
+    }
+    Ok(mismatches.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{set_permissions, write, Permissions};
+    use std::os::unix::fs::{symlink, PermissionsExt};
+    use tempfile::TempDir;
+
+    // sha256("hello\n")
+    const HELLO_SHA256: &str = "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03";
+
+    fn expect() -> FileExpectation {
+        FileExpectation {
+            exists: true,
+            follow_symlinks: true,
+            ..Default::default()
+        }
+    }
+
+    fn fixture() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("sshd_config");
+        write(&file, "hello\n").unwrap();
+        set_permissions(&file, Permissions::from_mode(0o600)).unwrap();
+        symlink(&file, dir.path().join("link")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_values() {
+        assert_eq!("0644".parse::<FileMode>().unwrap(), FileMode(0o644));
+        assert_eq!("4755".parse::<FileMode>().unwrap(), FileMode(0o4755));
+        assert!("0998".parse::<FileMode>().is_err());
+        assert!("77777".parse::<FileMode>().is_err());
+        assert!(HELLO_SHA256.to_uppercase().parse::<Sha256Digest>().is_ok());
+        assert!("abc".parse::<Sha256Digest>().is_err());
+    }
+
+    #[test]
+    fn test_existence() {
+        let dir = fixture();
+        let missing = dir.path().join("missing");
+        assert!(check_file(&dir.path().join("sshd_config"), &expect()).unwrap());
+        assert!(!check_file(&missing, &expect()).unwrap());
+        let absent = FileExpectation {
+            exists: false,
+            ..expect()
+        };
+        assert!(check_file(&missing, &absent).unwrap());
+        assert!(!check_file(&dir.path().join("sshd_config"), &absent).unwrap());
+    }
+
+    #[test]
+    fn test_mode_owner_digest() {
+        let dir = fixture();
+        let path = dir.path().join("sshd_config");
+        let uid = nix::unistd::getuid().as_raw();
+
+        let ok = FileExpectation {
+            mode: Some(FileMode(0o600)),
+            owner: Some(uid.to_string()),
+            group: Some(nix::unistd::getgid().as_raw().to_string()),
+            sha256: Some(HELLO_SHA256.parse().unwrap()),
+            ..expect()
+        };
+        assert!(check_file(&path, &ok).unwrap());
+
+        let wrong = FileExpectation {
+            mode: Some(FileMode(0o644)),
+            owner: Some((uid + 1).to_string()),
+            sha256: Some("0".repeat(64).parse().unwrap()),
+            ..expect()
+        };
+        let meta = metadata(&path).unwrap();
+        assert_eq!(check_metadata(&path, &meta, &wrong).len(), 3);
+    }
+
+    #[test]
+    fn test_symlinks() {
+        let dir = fixture();
+        let link = dir.path().join("link");
+        let digest = FileExpectation {
+            sha256: Some(HELLO_SHA256.parse().unwrap()),
+            ..expect()
+        };
+        assert!(check_file(&link, &digest).unwrap());
+        assert!(!check_file(
+            &link,
+            &FileExpectation {
+                follow_symlinks: false,
+                ..digest
+            }
+        )
+        .unwrap());
+
+        let mode = FileExpectation {
+            mode: Some(FileMode(0o600)),
+            ..expect()
+        };
+        assert!(check_file(&link, &mode).unwrap());
+        assert!(!check_file(
+            &link,
+            &FileExpectation {
+                follow_symlinks: false,
+                ..mode
+            }
+        )
+        .unwrap());
+    }
+}
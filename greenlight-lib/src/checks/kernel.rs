@@ -0,0 +1,160 @@
+use crate::checks::pattern::Pattern;
+use crate::errors::GreenlightError;
+use nix::sys::utsname::uname;
+use semver::{Version, VersionReq};
+use std::fs::read_dir;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+const USR_LIB_MODULES: &str = "/usr/lib/modules";
+
+/// Extracts the upstream `major.minor.patch` from a kernel release such as
+/// `5.14.0-427.13.1.el9_4.x86_64`; the distro suffix is ignored so it does not
+/// turn into a semver pre-release.
+pub fn release_version(release: &str) -> Option<Version> {
+    let upstream = release.split(['-', '+']).next()?;
+    let mut parts = upstream.split('.').map(|part| part.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some(Version::new(major, minor, patch))
+}
+
+/// Kernel releases shipped in the deployment, i.e. `modules/<release>` directories with a `vmlinuz`.
+fn shipped_kernels(modules: &Path) -> Result<Vec<String>, GreenlightError> {
+    let mut releases = Vec::new();
+    for entry in read_dir(modules)? {
+        let entry = entry?;
+        if entry.path().join("vmlinuz").is_file() {
+            releases.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    releases.sort();
+    Ok(releases)
+}
+
+/// Validates a kernel `release` against a version range, a regex and the kernels in `modules`.
+pub fn check_kernel_release(
+    release: &str,
+    version: Option<&VersionReq>,
+    pattern: Option<&Pattern>,
+    modules: Option<&Path>,
+) -> Result<bool, GreenlightError> {
+    let mut ok = true;
+
+    if let Some(req) = version {
+        match release_version(release) {
+            Some(parsed) if req.matches(&parsed) => {
+                debug!("Kernel {} ({}) satisfies {}", release, parsed, req);
+            }
+            Some(parsed) => {
+                error!(
+                    "❌ Kernel {} ({}) does not satisfy {}",
+                    release, parsed, req
+                );
+                ok = false;
+            }
+            None => {
+                error!("❌ Cannot parse a version from kernel release {}", release);
+                ok = false;
+            }
+        }
+    }
+
+    if let Some(pattern) = pattern {
+        if !pattern.is_match(release) {
+            error!("❌ Kernel {} does not match /{}/", release, pattern);
+            ok = false;
+        }
+    }
+
+    if let Some(modules) = modules {
+        if !modules.is_dir() {
+            error!("❌ {} does not exist", modules.display());
+            return Ok(false);
+        }
+        let shipped = shipped_kernels(modules)?;
+        if !shipped.iter().any(|k| k == release) {
+            error!(
+                "❌ Running kernel {} is not the one shipped in the deployment ({})",
+                release,
+                if shipped.is_empty() {
+                    "none found".to_string()
+                } else {
+                    shipped.join(", ")
+                }
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!("✅ Kernel {} matches expectations", release);
+    }
+    Ok(ok)
+}
+
+pub fn check_kernel_version(
+    version: Option<&VersionReq>,
+    pattern: Option<&Pattern>,
+    match_deployment: bool,
+) -> Result<bool, GreenlightError> {
+    let uts = uname().map_err(|e| GreenlightError::CheckFailed(e.to_string()))?;
+    let release = uts.release().to_string_lossy();
+    check_kernel_release(
+        &release,
+        version,
+        pattern,
+        match_deployment.then(|| Path::new(USR_LIB_MODULES)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    const RELEASE: &str = "5.14.0-427.13.1.el9_4.x86_64";
+
+    #[test]
+    fn test_release_version() {
+        assert_eq!(release_version(RELEASE), Some(Version::new(5, 14, 0)));
+        assert_eq!(
+            release_version("6.18.44-fc-v139"),
+            Some(Version::new(6, 18, 44))
+        );
+        assert_eq!(release_version("6.9"), Some(Version::new(6, 9, 0)));
+        assert_eq!(release_version("custom"), None);
+    }
+
+    #[test]
+    fn test_version_and_pattern() {
+        let req: VersionReq = ">=5.14, <6".parse().unwrap();
+        let el9: Pattern = r"\.el9(_\d+)?\.".parse().unwrap();
+        let rt: Pattern = r"\+rt".parse().unwrap();
+        assert!(check_kernel_release(RELEASE, Some(&req), Some(&el9), None).unwrap());
+        assert!(!check_kernel_release(RELEASE, Some(&">=6".parse().unwrap()), None, None).unwrap());
+        assert!(!check_kernel_release(RELEASE, None, Some(&rt), None).unwrap());
+    }
+
+    #[test]
+    fn test_match_deployment() {
+        let root = TempDir::new().unwrap();
+        let shipped = root.path().join("5.14.0-503.el9.x86_64");
+        create_dir_all(&shipped).unwrap();
+        write(shipped.join("vmlinuz"), "").unwrap();
+        // Leftover module directory without a kernel image.
+        create_dir_all(root.path().join(RELEASE)).unwrap();
+
+        assert!(
+            check_kernel_release("5.14.0-503.el9.x86_64", None, None, Some(root.path())).unwrap()
+        );
+        assert!(!check_kernel_release(RELEASE, None, None, Some(root.path())).unwrap());
+    }
+
+    #[test]
+    fn test_check_kernel_version_runs() {
+        assert!(check_kernel_version(None, None, false).unwrap());
+    }
+}
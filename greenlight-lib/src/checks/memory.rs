@@ -0,0 +1,227 @@
+use crate::checks::sysfs::read_value;
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::{fmt, str::FromStr};
+use tracing::{debug, error, info};
+
+const PROC_MEMINFO: &str = "/proc/meminfo";
+const SYS_HUGEPAGES: &str = "/sys/kernel/mm/hugepages";
+
+/// Huge page size in kB, written as `2M`, `1G` or `2048kB`.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
+pub struct HugePageSize(pub u64);
+
+impl FromStr for HugePageSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid huge page size '{}', expected e.g. 2M or 1G", s);
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+        let multiplier = match unit.to_ascii_lowercase().as_str() {
+            "k" | "kb" => 1,
+            "m" | "mb" => 1024,
+            "g" | "gb" => 1024 * 1024,
+            _ => return Err(invalid()),
+        };
+        match number
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| n.checked_mul(multiplier))
+        {
+            Some(kb) if kb > 0 => Ok(HugePageSize(kb)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl TryFrom<String> for HugePageSize {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for HugePageSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            kb if kb % (1024 * 1024) == 0 => write!(f, "{}G", kb / (1024 * 1024)),
+            kb if kb % 1024 == 0 => write!(f, "{}M", kb / 1024),
+            kb => write!(f, "{}kB", kb),
+        }
+    }
+}
+
+/// Minimum number of huge pages of a given size that must be reserved.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
+pub struct HugePages {
+    pub size: HugePageSize,
+    pub count: u64,
+}
+
+/// Parses `/proc/meminfo` into values in kB (or plain counts for `HugePages_*`).
+pub fn parse_meminfo(content: &str) -> BTreeMap<String, u64> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, rest) = line.split_once(':')?;
+            let value = rest.split_whitespace().next()?.parse().ok()?;
+            Some((key.to_string(), value))
+        })
+        .collect()
+}
+
+/// Validates memory figures against `/proc/meminfo` content and a `/sys/kernel/mm/hugepages` root.
+pub fn check_memory_in(
+    meminfo: &str,
+    hugepages_root: &Path,
+    min_available_mb: Option<u64>,
+    max_swap_used_mb: Option<u64>,
+    hugepages: Option<&HugePages>,
+) -> Result<bool, GreenlightError> {
+    let meminfo = parse_meminfo(meminfo);
+    let field = |key: &str| {
+        meminfo.get(key).copied().ok_or_else(|| {
+            GreenlightError::CheckFailed(format!("{} missing from /proc/meminfo", key))
+        })
+    };
+
+    let mut ok = true;
+
+    if let Some(min) = min_available_mb {
+        let available = field("MemAvailable")? / 1024;
+        debug!("MemAvailable: {} MB", available);
+        if available < min {
+            error!(
+                "❌ {} MB memory available, below minimum {} MB",
+                available, min
+            );
+            ok = false;
+        }
+    }
+
+    if let Some(max) = max_swap_used_mb {
+        let used = field("SwapTotal")?.saturating_sub(field("SwapFree")?) / 1024;
+        debug!("Swap used: {} MB", used);
+        if used > max {
+            error!("❌ {} MB swap used, above maximum {} MB", used, max);
+            ok = false;
+        }
+    }
+
+    if let Some(expected) = hugepages {
+        let dir = hugepages_root.join(format!("hugepages-{}kB", expected.size.0));
+        if !dir.is_dir() {
+            error!(
+                "❌ Huge page size {} is not supported by this kernel",
+                expected.size
+            );
+            ok = false;
+        } else {
+            let reserved: u64 = read_value(&dir.join("nr_hugepages"))?;
+            debug!("{} huge pages: {} reserved", expected.size, reserved);
+            if reserved < expected.count {
+                error!(
+                    "❌ {} {} huge pages reserved, expected at least {}",
+                    reserved, expected.size, expected.count
+                );
+                ok = false;
+            }
+        }
+    }
+
+    if ok {
+        info!("✅ Memory matches expectations");
+    }
+    Ok(ok)
+}
+
+pub fn check_memory(
+    min_available_mb: Option<u64>,
+    max_swap_used_mb: Option<u64>,
+    hugepages: Option<&HugePages>,
+) -> Result<bool, GreenlightError> {
+    check_memory_in(
+        &read_to_string(PROC_MEMINFO)?,
+        Path::new(SYS_HUGEPAGES),
+        min_available_mb,
+        max_swap_used_mb,
+        hugepages,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    const MEMINFO: &str = "\
+MemTotal:        6158152 kB
+MemFree:          243372 kB
+MemAvailable:    5426392 kB
+SwapTotal:       2097148 kB
+SwapFree:        1048572 kB
+HugePages_Total:       8
+";
+
+    fn fake_hugepages(size_kb: u64, reserved: u64) -> TempDir {
+        let root = TempDir::new().unwrap();
+        let dir = root.path().join(format!("hugepages-{}kB", size_kb));
+        create_dir_all(&dir).unwrap();
+        write(dir.join("nr_hugepages"), format!("{}\n", reserved)).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_hugepage_size() {
+        assert_eq!("2M".parse::<HugePageSize>().unwrap(), HugePageSize(2048));
+        assert_eq!("1G".parse::<HugePageSize>().unwrap(), HugePageSize(1048576));
+        assert_eq!(
+            "2048kB".parse::<HugePageSize>().unwrap(),
+            HugePageSize(2048)
+        );
+        assert_eq!(HugePageSize(1048576).to_string(), "1G");
+        assert!("1T".parse::<HugePageSize>().is_err());
+        assert!("G".parse::<HugePageSize>().is_err());
+        assert!("18446744073709551615G".parse::<HugePageSize>().is_err());
+    }
+
+    #[test]
+    fn test_parse_meminfo() {
+        let meminfo = parse_meminfo(MEMINFO);
+        assert_eq!(meminfo["MemAvailable"], 5426392);
+        assert_eq!(meminfo["HugePages_Total"], 8);
+    }
+
+    #[test]
+    fn test_available_and_swap() {
+        let root = TempDir::new().unwrap();
+        let check =
+            |available, swap| check_memory_in(MEMINFO, root.path(), available, swap, None).unwrap();
+        // 5299 MB available, 1023 MB swap used.
+        assert!(check(Some(5000), Some(1024)));
+        assert!(!check(Some(6000), None));
+        assert!(!check(None, Some(512)));
+    }
+
+    #[test]
+    fn test_hugepages() {
+        let root = fake_hugepages(1048576, 8);
+        let hugepages = |size: &str, count| HugePages {
+            size: size.parse().unwrap(),
+            count,
+        };
+        let check = |expected: HugePages| {
+            check_memory_in(MEMINFO, root.path(), None, None, Some(&expected)).unwrap()
+        };
+        assert!(check(hugepages("1G", 8)));
+        assert!(!check(hugepages("1G", 16)));
+        assert!(!check(hugepages("2M", 1)));
+    }
+}
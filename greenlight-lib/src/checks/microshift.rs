@@ -0,0 +1,131 @@
+use crate::errors::GreenlightError;
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::path::Path;
+use tokio::process::Command;
+use tracing::{debug, error, info};
+
+const MICROSHIFT_BIN: &str = "/usr/bin/microshift";
+const RELEASE_INFO_DIR: &str = "/usr/share/microshift/release";
+
+/// Subset of `microshift version -o json` output.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VersionInfo {
+    git_version: String,
+}
+
+/// Parses the output of `microshift version -o json` into a semantic version.
+///
+/// RPM-style pre-release separators (`4.18.0~rc.1`) are normalized to semver (`4.18.0-rc.1`).
+pub fn parse_version_output(json: &str) -> Result<Version, GreenlightError> {
+    let info: VersionInfo = serde_json::from_str(json)?;
+    let normalized = info
+        .git_version
+        .trim()
+        .trim_start_matches('v')
+        .replace('~', "-");
+    Version::parse(&normalized).map_err(|e| {
+        GreenlightError::CheckFailed(format!(
+            "Invalid MicroShift version '{}': {}",
+            info.git_version, e
+        ))
+    })
+}
+
+/// Returns true if the release-info package has dropped at least one `release-*.json` file.
+fn release_info_present(dir: &Path) -> Result<bool, GreenlightError> {
+    if !dir.is_dir() {
+        return Ok(false);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with("release-") && name.ends_with(".json") {
+            debug!("Found MicroShift release info: {}", name);
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+pub async fn installed_version() -> Result<Version, GreenlightError> {
+    let output = Command::new(MICROSHIFT_BIN)
+        .args(["version", "-o", "json"])
+        .output()
+        .await
+        .map_err(GreenlightError::Io)?;
+
+    if !output.status.success() {
+        return Err(GreenlightError::CheckFailed(format!(
+            "microshift version exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    parse_version_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+pub async fn is_microshift_installed(
+    version: Option<&VersionReq>,
+) -> Result<bool, GreenlightError> {
+    if !Path::new(MICROSHIFT_BIN).is_file() {
+        error!("❌ MicroShift binary not found at {}", MICROSHIFT_BIN);
+        return Ok(false);
+    }
+
+    if !release_info_present(Path::new(RELEASE_INFO_DIR))? {
+        error!(
+            "❌ microshift-release-info not installed: no release-*.json in {}",
+            RELEASE_INFO_DIR
+        );
+        return Ok(false);
+    }
+
+    let installed = installed_version().await?;
+    debug!("Installed MicroShift version: {}", installed);
+
+    match version {
+        Some(req) if !req.matches(&installed) => {
+            error!(
+                "❌ MicroShift version {} does not satisfy '{}'",
+                installed, req
+            );
+            Ok(false)
+        }
+        Some(req) => {
+            info!("✅ MicroShift version {} satisfies '{}'", installed, req);
+            Ok(true)
+        }
+        None => {
+            info!("✅ MicroShift {} is installed", installed);
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_output() {
+        let json = r#"{"major":"4","minor":"18","gitVersion":"4.18.2","gitCommit":"abc"}"#;
+        let version = parse_version_output(json).unwrap();
+        assert_eq!(version, Version::new(4, 18, 2));
+
+        let req = VersionReq::parse(">=4.18, <4.19").unwrap();
+        assert!(req.matches(&version));
+    }
+
+    #[test]
+    fn test_parse_rpm_prerelease_version() {
+        let json = r#"{"gitVersion":"4.19.0~rc.1"}"#;
+        let version = parse_version_output(json).unwrap();
+        assert_eq!(version.pre.as_str(), "rc.1");
+        assert!(!VersionReq::parse(">=4.18, <4.19")
+            .unwrap()
+            .matches(&version));
+    }
+}
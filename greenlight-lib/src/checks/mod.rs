@@ -1,6 +1,28 @@
+pub mod bootc;
 pub mod check;
+pub mod cmdline;
+pub mod config_value;
+pub mod contents;
+pub mod context;
+pub mod cpu;
 // Re-export to make `Check` available as `checks::Check` if needed
 pub use check::Check;
+pub use context::CheckContext;
+pub mod file;
+pub mod kernel;
+pub mod memory;
+pub mod microshift;
+pub mod modules;
+pub mod mount;
 pub mod network;
+pub mod pattern;
+pub mod process;
 pub mod rootfs;
+pub mod routes;
+pub mod selinux;
+pub mod sriov;
+pub mod swap;
+pub mod sysctl;
+mod sysfs;
+pub mod topology;
 pub mod unit;
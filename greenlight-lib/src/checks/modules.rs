@@ -0,0 +1,179 @@
+use crate::errors::GreenlightError;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+const PROC_MODULES: &str = "/proc/modules";
+const SYS_MODULE: &str = "/sys/module";
+
+/// Module names use `_` internally even when loaded as `foo-bar`.
+fn normalize(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+/// Names of the live modules listed in `/proc/modules`.
+pub fn parse_proc_modules(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            // name size refcount deps state address
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.get(4) {
+                Some(&"Unloading") => None,
+                _ => fields.first().map(|name| name.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Validates loaded modules and parameters against `/proc/modules` content and a `/sys/module` root.
+///
+/// Built-in drivers never show up in `/proc/modules` but do have a `/sys/module` entry, so
+/// either source counts as loaded. `parameters` keys are `module.parameter`.
+pub fn check_kernel_modules_in(
+    proc_modules: &str,
+    sys_module: &Path,
+    loaded: &[String],
+    absent: &[String],
+    parameters: &BTreeMap<String, String>,
+) -> Result<bool, GreenlightError> {
+    let live = parse_proc_modules(proc_modules);
+    debug!("{} modules loaded", live.len());
+    let is_loaded = |name: &str| live.iter().any(|m| *m == name) || sys_module.join(name).is_dir();
+
+    let mut ok = true;
+    for module in loaded {
+        if !is_loaded(&normalize(module)) {
+            error!("❌ Kernel module '{}' is not loaded", module);
+            ok = false;
+        }
+    }
+    for module in absent {
+        if is_loaded(&normalize(module)) {
+            error!("❌ Kernel module '{}' is loaded", module);
+            ok = false;
+        }
+    }
+
+    for (key, expected) in parameters {
+        let Some((module, parameter)) = key.split_once('.') else {
+            error!(
+                "❌ Invalid module parameter '{}', expected module.parameter",
+                key
+            );
+            ok = false;
+            continue;
+        };
+        let path = sys_module
+            .join(normalize(module))
+            .join("parameters")
+            .join(parameter);
+        if !path.is_file() {
+            error!(
+                "❌ Module parameter '{}' not found at {}",
+                key,
+                path.display()
+            );
+            ok = false;
+            continue;
+        }
+        let value = read_to_string(&path)?;
+        let value = value.trim();
+        if value == expected {
+            debug!("✅ Module parameter {}={}", key, value);
+        } else {
+            error!(
+                "❌ Module parameter '{}' mismatch: expected {}, got {}",
+                key, expected, value
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!("✅ Kernel modules match expectations");
+    }
+    Ok(ok)
+}
+
+pub fn check_kernel_modules(
+    loaded: &[String],
+    absent: &[String],
+    parameters: &BTreeMap<String, String>,
+) -> Result<bool, GreenlightError> {
+    check_kernel_modules_in(
+        &read_to_string(PROC_MODULES)?,
+        Path::new(SYS_MODULE),
+        loaded,
+        absent,
+        parameters,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    const PROC_MODULES: &str = "\
+mlx5_core 2506752 1 mlx5_ib, Live 0xffffffffc0a00000
+can_raw 20480 0 - Live 0xffffffffc0900000
+nouveau 2842624 0 - Unloading 0xffffffffc1000000
+";
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn fake_sys_module() -> TempDir {
+        let root = TempDir::new().unwrap();
+        let params = root.path().join("mlx5_core").join("parameters");
+        create_dir_all(&params).unwrap();
+        write(params.join("prof_sel"), "2\n").unwrap();
+        // Built-in driver: no /proc/modules entry.
+        create_dir_all(root.path().join("virtio_net")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_parse_proc_modules() {
+        assert_eq!(parse_proc_modules(PROC_MODULES), ["mlx5_core", "can_raw"]);
+    }
+
+    #[test]
+    fn test_loaded_and_absent() {
+        let root = fake_sys_module();
+        let none = BTreeMap::new();
+        let check = |loaded: &[&str], absent: &[&str]| {
+            check_kernel_modules_in(
+                PROC_MODULES,
+                root.path(),
+                &strings(loaded),
+                &strings(absent),
+                &none,
+            )
+            .unwrap()
+        };
+        assert!(check(
+            &["mlx5_core", "can-raw", "virtio_net"],
+            &["nouveau", "floppy"]
+        ));
+        assert!(!check(&["can_bcm"], &[]));
+        assert!(!check(&[], &["virtio_net"]));
+    }
+
+    #[test]
+    fn test_parameters() {
+        let root = fake_sys_module();
+        let check = |key: &str, value: &str| {
+            let parameters = BTreeMap::from([(key.to_string(), value.to_string())]);
+            check_kernel_modules_in(PROC_MODULES, root.path(), &[], &[], &parameters).unwrap()
+        };
+        assert!(check("mlx5_core.prof_sel", "2"));
+        assert!(!check("mlx5_core.prof_sel", "1"));
+        assert!(!check("mlx5_core.debug_mask", "0"));
+        assert!(!check("prof_sel", "2"));
+    }
+}
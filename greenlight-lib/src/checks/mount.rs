@@ -0,0 +1,261 @@
+use crate::errors::GreenlightError;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+/// One line of `/proc/self/mountinfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountInfo {
+    pub mount_point: String,
+    pub fstype: String,
+    pub source: String,
+    /// Per-mount options (`ro`, `nosuid`, ...), which may differ between bind mounts.
+    pub mount_options: Vec<String>,
+    /// Options of the underlying superblock, including filesystem-specific ones.
+    pub super_options: Vec<String>,
+}
+
+/// Options the kernel reports per mount rather than per superblock.
+const PER_MOUNT_OPTIONS: &[&str] = &[
+    "ro",
+    "rw",
+    "nosuid",
+    "nodev",
+    "noexec",
+    "noatime",
+    "nodiratime",
+    "relatime",
+    "strictatime",
+    "nosymfollow",
+];
+
+impl MountInfo {
+    /// The option list `option` is reported in: per-mount for `ro`/`rw` and VFS flags,
+    /// superblock for everything else.
+    fn options_for(&self, option: &str) -> &[String] {
+        if PER_MOUNT_OPTIONS.contains(&option) {
+            &self.mount_options
+        } else {
+            &self.super_options
+        }
+    }
+}
+
+/// Decodes the octal escapes (`\040` for space, ...) the kernel uses in mountinfo paths.
+pub(crate) fn unescape(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 4 <= bytes.len() {
+            let digits = std::str::from_utf8(&bytes[i + 1..i + 4]).ok();
+            if let Some(value) = digits.and_then(|d| u8::from_str_radix(d, 8).ok()) {
+                out.push(value);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn parse_line(line: &str) -> Option<MountInfo> {
+    // id parent major:minor root mount-point options [optional...] - fstype source super-options
+    let (head, tail) = line.split_once(" - ")?;
+    let head: Vec<&str> = head.split_whitespace().collect();
+    let tail: Vec<&str> = tail.split_whitespace().collect();
+    if head.len() < 6 || tail.len() < 2 {
+        return None;
+    }
+
+    let split = |options: &str| options.split(',').map(str::to_string).collect();
+
+    Some(MountInfo {
+        mount_point: unescape(head[4]),
+        fstype: tail[0].to_string(),
+        source: unescape(tail[1]),
+        mount_options: split(head[5]),
+        super_options: tail.get(2).map(|o| split(o)).unwrap_or_default(),
+    })
+}
+
+pub fn parse_mountinfo(content: &str) -> Vec<MountInfo> {
+    content.lines().filter_map(parse_line).collect()
+}
+
+/// Checks the top-most mount at `path` against the expected fstype, options and source.
+pub fn check_mount_in(
+    mounts: &[MountInfo],
+    path: &Path,
+    fstype: Option<&str>,
+    options: &[String],
+    source: Option<&str>,
+) -> bool {
+    // Later entries are mounted on top of earlier ones at the same mount point. Paths are
+    // compared component-wise so `/var/` and `/var` are the same mount point.
+    let Some(mount) = mounts
+        .iter()
+        .rev()
+        .find(|m| Path::new(&m.mount_point) == path)
+    else {
+        error!("❌ '{}' is not a mount point", path.display());
+        return false;
+    };
+    let path = path.display();
+    debug!("Mount at '{}': {:?}", path, mount);
+
+    let mut ok = true;
+    if let Some(expected) = fstype {
+        if mount.fstype != expected {
+            error!(
+                "❌ '{}' fstype mismatch: expected {}, got {}",
+                path, expected, mount.fstype
+            );
+            ok = false;
+        }
+    }
+    if let Some(expected) = source {
+        if mount.source != expected {
+            error!(
+                "❌ '{}' source mismatch: expected {}, got {}",
+                path, expected, mount.source
+            );
+            ok = false;
+        }
+    }
+    for option in options {
+        let actual = mount.options_for(option);
+        if !actual.contains(option) {
+            error!(
+                "❌ '{}' is missing mount option '{}' (has {})",
+                path,
+                option,
+                actual.join(",")
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!(
+            "✅ '{}' is mounted as expected ({} from {})",
+            path, mount.fstype, mount.source
+        );
+    }
+    ok
+}
+
+pub fn check_mount(
+    path: &Path,
+    fstype: Option<&str>,
+    options: &[String],
+    source: Option<&str>,
+) -> Result<bool, GreenlightError> {
+    let mounts = parse_mountinfo(&read_to_string("/proc/self/mountinfo")?);
+    Ok(check_mount_in(&mounts, path, fstype, options, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOUNTINFO: &str = "\
+22 1 0:21 / / ro,relatime shared:1 - composefs composefs ro,lowerdir=/sysroot/ostree
+23 22 253:0 / /sysroot ro,relatime shared:2 - xfs /dev/vda4 ro,attr2,inode64
+24 22 253:0 /ostree/deploy/default/var /var rw,relatime shared:3 - xfs /dev/vda4 rw,attr2
+25 24 253:1 / /var/lib/my\\040data rw,nosuid,nodev shared:4 master:1 - ext4 /dev/vdb1 rw
+26 22 0:5 / /usr ro,nosuid - overlay overlay rw
+";
+
+    #[test]
+    fn test_parse_mountinfo() {
+        let mounts = parse_mountinfo(MOUNTINFO);
+        assert_eq!(mounts.len(), 5);
+        assert_eq!(mounts[0].fstype, "composefs");
+        assert_eq!(mounts[3].mount_point, "/var/lib/my data");
+        assert_eq!(mounts[3].source, "/dev/vdb1");
+        assert!(mounts[3].mount_options.contains(&"nosuid".to_string()));
+        assert_eq!(mounts[3].super_options, ["rw"]);
+        assert!(mounts[1].super_options.contains(&"inode64".to_string()));
+    }
+
+    #[test]
+    fn test_check_mount() {
+        let mounts = parse_mountinfo(MOUNTINFO);
+        let opts = |o: &[&str]| o.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert!(check_mount_in(
+            &mounts,
+            Path::new("/"),
+            Some("composefs"),
+            &opts(&["ro"]),
+            None
+        ));
+        assert!(check_mount_in(
+            &mounts,
+            Path::new("/var"),
+            Some("xfs"),
+            &opts(&["rw"]),
+            Some("/dev/vda4")
+        ));
+        assert!(check_mount_in(
+            &mounts,
+            Path::new("/var/lib/my data"),
+            Some("ext4"),
+            &opts(&["nosuid", "nodev"]),
+            None
+        ));
+        assert!(!check_mount_in(
+            &mounts,
+            Path::new("/var"),
+            Some("ext4"),
+            &[],
+            None
+        ));
+        assert!(!check_mount_in(
+            &mounts,
+            Path::new("/sysroot"),
+            None,
+            &opts(&["rw"]),
+            None
+        ));
+        assert!(check_mount_in(
+            &mounts,
+            Path::new("/sysroot"),
+            None,
+            &opts(&["ro", "inode64"]),
+            None
+        ));
+        // Read-only bind mount on a read-write superblock.
+        assert!(!check_mount_in(
+            &mounts,
+            Path::new("/usr"),
+            None,
+            &opts(&["rw"]),
+            None
+        ));
+        assert!(check_mount_in(
+            &mounts,
+            Path::new("/usr"),
+            None,
+            &opts(&["ro", "nosuid"]),
+            None
+        ));
+        assert!(check_mount_in(
+            &mounts,
+            Path::new("/var/"),
+            Some("xfs"),
+            &[],
+            None
+        ));
+        assert!(!check_mount_in(
+            &mounts,
+            Path::new("/data"),
+            None,
+            &[],
+            None
+        ));
+    }
+}
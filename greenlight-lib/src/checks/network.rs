@@ -1,9 +1,286 @@
 use crate::errors::GreenlightError;
 use futures::TryStreamExt;
-use rtnetlink::{new_connection, packet_route::link::LinkAttribute};
+use rtnetlink::{
+    new_connection,
+    packet_route::{
+        address::{AddressAttribute, AddressMessage, AddressScope},
+        link::{LinkAttribute, LinkExtentMask, LinkInfo, LinkMessage},
+        route::RouteMessage,
+        AddressFamily,
+    },
+    Handle, IpVersion, RouteMessageBuilder,
+};
 use serde::Deserialize;
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
 use tracing::{debug, error, info};
 
+/// A single rtnetlink connection shared by every network check in a run.
+#[derive(Debug, Clone)]
+pub struct Netlink {
+    handle: Handle,
+}
+
+impl Netlink {
+    /// Opens the netlink socket and spawns its connection task on the current runtime.
+    pub fn connect() -> Result<Self, GreenlightError> {
+        let (conn, handle, _) = new_connection()?;
+        tokio::spawn(conn);
+        Ok(Netlink { handle })
+    }
+
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Fetches every link and every address on the system, one dump each.
+    pub async fn snapshot(&self) -> Result<LinkSnapshot, GreenlightError> {
+        let links: Vec<LinkMessage> = self.handle.link().get().execute().try_collect().await?;
+        let addresses: Vec<AddressMessage> =
+            self.handle.address().get().execute().try_collect().await?;
+        debug!(
+            "Fetched {} links and {} addresses from netlink",
+            links.len(),
+            addresses.len()
+        );
+        Ok(LinkSnapshot { links, addresses })
+    }
+
+    /// Fetches the link named `name` including its `IFLA_VFINFO_LIST`, which is only sent on request.
+    pub async fn link_with_vfs(&self, name: &str) -> Result<Option<LinkMessage>, GreenlightError> {
+        let links: Vec<LinkMessage> = self
+            .handle
+            .link()
+            .get()
+            .set_filter_mask(AddressFamily::Unspec, vec![LinkExtentMask::Vf])
+            .execute()
+            .try_collect()
+            .await?;
+        Ok(links.into_iter().find(|link| link_name(link) == Some(name)))
+    }
+
+    /// Dumps the IPv4 or IPv6 routes of every routing table.
+    pub async fn routes(&self, version: IpVersion) -> Result<Vec<RouteMessage>, GreenlightError> {
+        let request = match version {
+            IpVersion::V4 => RouteMessageBuilder::<Ipv4Addr>::new().build(),
+            IpVersion::V6 => RouteMessageBuilder::<Ipv6Addr>::new().build(),
+        };
+        let routes: Vec<RouteMessage> = self
+            .handle
+            .route()
+            .get(request)
+            .execute()
+            .try_collect()
+            .await?;
+        debug!("Fetched {} {:?} routes from netlink", routes.len(), version);
+        Ok(routes)
+    }
+}
+
+/// Point-in-time view of all links, used to validate many interfaces without re-querying.
+#[derive(Debug, Default, Clone)]
+pub struct LinkSnapshot {
+    links: Vec<LinkMessage>,
+    addresses: Vec<AddressMessage>,
+}
+
+impl LinkSnapshot {
+    pub fn new(links: Vec<LinkMessage>, addresses: Vec<AddressMessage>) -> Self {
+        LinkSnapshot { links, addresses }
+    }
+
+    /// Addresses assigned to the link with the given index.
+    pub fn addresses_of(&self, index: u32) -> impl Iterator<Item = &AddressMessage> {
+        self.addresses
+            .iter()
+            .filter(move |address| address.header.index == index)
+    }
+
+    pub fn by_name(&self, name: &str) -> Option<&LinkMessage> {
+        self.links.iter().find(|link| link_name(link) == Some(name))
+    }
+
+    pub fn by_index(&self, index: u32) -> Option<&LinkMessage> {
+        self.links.iter().find(|link| link.header.index == index)
+    }
+}
+
+pub fn link_name(link: &LinkMessage) -> Option<&str> {
+    link.attributes.iter().find_map(|attr| match attr {
+        LinkAttribute::IfName(name) => Some(name.as_str()),
+        _ => None,
+    })
+}
+
+/// An IP address with prefix length, written as `192.168.1.10/24` or `fd00::1/64`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(try_from = "String")]
+pub struct IpCidr {
+    pub addr: IpAddr,
+    pub prefix_len: u8,
+}
+
+impl IpCidr {
+    /// Extracts the assigned address from an rtnetlink address message.
+    ///
+    /// `IFA_LOCAL` is preferred because on point-to-point IPv4 links `IFA_ADDRESS` is the peer.
+    pub fn from_message(message: &AddressMessage) -> Option<Self> {
+        let local = message.attributes.iter().find_map(|attr| match attr {
+            AddressAttribute::Local(addr) => Some(*addr),
+            _ => None,
+        });
+        let address = message.attributes.iter().find_map(|attr| match attr {
+            AddressAttribute::Address(addr) => Some(*addr),
+            _ => None,
+        });
+        local.or(address).map(|addr| IpCidr {
+            addr,
+            prefix_len: message.header.prefix_len,
+        })
+    }
+}
+
+impl IpCidr {
+    /// Returns true if `other` lies entirely within this prefix.
+    pub fn contains(&self, other: &IpCidr) -> bool {
+        if self.prefix_len > other.prefix_len {
+            return false;
+        }
+        match (self.addr, other.addr) {
+            (IpAddr::V4(a), IpAddr::V4(b)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix_len))
+                    .unwrap_or(0);
+                u32::from(a) & mask == u32::from(b) & mask
+            }
+            (IpAddr::V6(a), IpAddr::V6(b)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix_len))
+                    .unwrap_or(0);
+                u128::from(a) & mask == u128::from(b) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpCidr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix_len) = s
+            .split_once('/')
+            .ok_or_else(|| format!("missing prefix length in '{}'", s))?;
+        let addr: IpAddr = addr
+            .parse()
+            .map_err(|e| format!("invalid address '{}': {}", addr, e))?;
+        let prefix_len: u8 = prefix_len
+            .parse()
+            .map_err(|e| format!("invalid prefix length '{}': {}", prefix_len, e))?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        if prefix_len > max {
+            return Err(format!(
+                "prefix length {} exceeds {} for {}",
+                prefix_len, max, addr
+            ));
+        }
+        Ok(IpCidr { addr, prefix_len })
+    }
+}
+
+impl TryFrom<String> for IpCidr {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+/// A MAC address to match: either all six octets, or a three-octet OUI prefix.
+#[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
+#[serde(try_from = "String")]
+pub struct MacPattern {
+    octets: Vec<u8>,
+}
+
+impl MacPattern {
+    pub fn is_oui(&self) -> bool {
+        self.octets.len() == 3
+    }
+
+    pub fn matches(&self, address: &[u8]) -> bool {
+        address.len() == 6 && address.starts_with(&self.octets)
+    }
+}
+
+impl FromStr for MacPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let octets = s
+            .split([':', '-'])
+            .map(|octet| match octet.len() {
+                2 => u8::from_str_radix(octet, 16).ok(),
+                _ => None,
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| format!("invalid MAC address '{}'", s))?;
+        match octets.len() {
+            3 | 6 => Ok(MacPattern { octets }),
+            n => Err(format!(
+                "MAC '{}' has {} octets, expected 6 (exact) or 3 (OUI prefix)",
+                s, n
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for MacPattern {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for MacPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_mac(&self.octets))?;
+        if self.is_oui() {
+            f.write_str(":*")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn format_mac(octets: &[u8]) -> String {
+    octets
+        .iter()
+        .map(|octet| format!("{:02x}", octet))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// The `IFLA_INFO_KIND` of a link (`vlan`, `bond`, `bridge`, ...), absent for physical NICs.
+pub fn link_kind(link: &LinkMessage) -> Option<String> {
+    link.attributes.iter().find_map(|attr| match attr {
+        LinkAttribute::LinkInfo(infos) => infos.iter().find_map(|info| match info {
+            LinkInfo::Kind(kind) => Some(kind.to_string()),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum InterfaceState {
@@ -12,7 +289,7 @@ pub enum InterfaceState {
     Absent,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Hash, Eq)]
 pub struct Interface {
     pub name: String,
     #[serde(default)]
@@ -21,17 +298,43 @@ pub struct Interface {
     pub mtu: Option<u32>,
     #[serde(default)]
     pub promisc: Option<bool>,
+    /// Addresses that must be assigned, including their prefix length.
+    #[serde(default)]
+    pub addresses: Vec<IpCidr>,
+    /// Require at least one non-link-local IPv4 address.
+    #[serde(default)]
+    pub require_ipv4: bool,
+    /// Require at least one non-link-local IPv6 address.
+    #[serde(default)]
+    pub require_ipv6: bool,
+    /// Exact MAC (`aa:bb:cc:dd:ee:ff`) or OUI prefix (`aa:bb:cc`).
+    #[serde(default)]
+    pub mac: Option<MacPattern>,
+    /// Link kind as reported by the kernel, e.g. `vlan`, `bond`, `bridge`, `veth`, `can`.
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// Maximum receive errors, a cumulative counter since the link was created.
+    #[serde(default)]
+    pub max_rx_errors: Option<u64>,
+    /// Maximum transmit errors, a cumulative counter since the link was created.
+    #[serde(default)]
+    pub max_tx_errors: Option<u64>,
+    /// Maximum dropped received packets, a cumulative counter since the link was created.
+    #[serde(default)]
+    pub max_rx_dropped: Option<u64>,
+    /// Maximum dropped transmitted packets, a cumulative counter since the link was created.
+    #[serde(default)]
+    pub max_tx_dropped: Option<u64>,
+    /// Maximum carrier up/down transitions, a cumulative counter since the link was created.
+    #[serde(default)]
+    pub max_carrier_changes: Option<u32>,
 }
 
 impl Interface {
-    pub async fn validate(&self) -> Result<bool, GreenlightError> {
+    pub fn validate(&self, links: &LinkSnapshot) -> bool {
         debug!("Validating interface: {:?}", self);
 
-        let (conn, handle, _) = new_connection()?;
-        tokio::spawn(conn);
-        let mut links = handle.link().get().match_name(self.name.clone()).execute();
-
-        if let Some(link) = links.try_next().await? {
+        if let Some(link) = links.by_name(&self.name) {
             debug!("Interface '{}' found", self.name);
 
             if let Some(expected_state) = &self.state {
@@ -40,7 +343,7 @@ impl Interface {
                         "❌ Interface '{}' state mismatch: expected {:?}, found different state",
                         self.name, expected_state
                     );
-                    return Ok(false);
+                    return false;
                 } else {
                     debug!(
                         "✅ Interface '{}' state matches expected: {:?}",
@@ -67,11 +370,11 @@ impl Interface {
                             "❌ MTU mismatch for '{}': expected {}, got {}",
                             self.name, expected_mtu, actual_mtu
                         );
-                        return Ok(false);
+                        return false;
                     }
                     None => {
                         error!("❌ MTU attribute not found for '{}'", self.name);
-                        return Ok(false);
+                        return false;
                     }
                 }
             }
@@ -93,32 +396,208 @@ impl Interface {
                             "❌ Promiscuity mismatch for '{}': expected {}, got {}",
                             self.name, expected_promisc, is_promisc
                         );
-                        return Ok(false);
+                        return false;
                     }
                     None => {
                         error!("❌ Promiscuity attribute not found for '{}'", self.name);
-                        return Ok(false);
+                        return false;
+                    }
+                }
+            }
+
+            if let Some(expected_mac) = &self.mac {
+                let actual_mac = link.attributes.iter().find_map(|attr| match attr {
+                    LinkAttribute::Address(bytes) => Some(bytes.as_slice()),
+                    _ => None,
+                });
+                match actual_mac {
+                    Some(mac) if expected_mac.matches(mac) => {
+                        debug!(
+                            "✅ MAC matches for '{}': expected {}, got {}",
+                            self.name,
+                            expected_mac,
+                            format_mac(mac)
+                        );
+                    }
+                    Some(mac) => {
+                        error!(
+                            "❌ MAC mismatch for '{}': expected {}, got {}",
+                            self.name,
+                            expected_mac,
+                            format_mac(mac)
+                        );
+                        return false;
+                    }
+                    None => {
+                        error!("❌ MAC address attribute not found for '{}'", self.name);
+                        return false;
                     }
                 }
             }
 
+            if let Some(expected_kind) = &self.kind {
+                match link_kind(link) {
+                    Some(kind) if kind == *expected_kind => {
+                        debug!("✅ Kind matches for '{}': {}", self.name, kind);
+                    }
+                    Some(kind) => {
+                        error!(
+                            "❌ Kind mismatch for '{}': expected {}, got {}",
+                            self.name, expected_kind, kind
+                        );
+                        return false;
+                    }
+                    None => {
+                        error!(
+                            "❌ Kind mismatch for '{}': expected {}, got a device without link kind",
+                            self.name, expected_kind
+                        );
+                        return false;
+                    }
+                }
+            }
+
+            if !self.match_addresses(link.header.index, links) {
+                return false;
+            }
+
+            if !self.match_counters(&link.attributes) {
+                return false;
+            }
+
             info!("✅ Interface '{}' passed all checks", self.name);
-            Ok(true)
+            true
         } else {
             debug!("Interface '{}' not found", self.name);
             if self.state == Some(InterfaceState::Absent) {
                 info!("✅ Interface '{}' is correctly absent", self.name);
-                Ok(true)
+                true
             } else {
                 error!(
                     "❌ Interface '{}' not found, but expected state was {:?}",
                     self.name, self.state
                 );
-                Ok(false)
+                false
             }
         }
     }
 
+    fn match_addresses(&self, index: u32, links: &LinkSnapshot) -> bool {
+        let assigned: Vec<(IpCidr, AddressScope)> = links
+            .addresses_of(index)
+            .filter_map(|message| {
+                IpCidr::from_message(message).map(|cidr| (cidr, message.header.scope))
+            })
+            .collect();
+        debug!("Addresses on '{}': {:?}", self.name, assigned);
+
+        let mut ok = true;
+        for expected in &self.addresses {
+            if assigned.iter().any(|(cidr, _)| cidr == expected) {
+                debug!("✅ Address {} present on '{}'", expected, self.name);
+            } else {
+                error!("❌ Address {} not assigned to '{}'", expected, self.name);
+                ok = false;
+            }
+        }
+
+        let has_routable = |want_v4: bool| {
+            assigned.iter().any(|(cidr, scope)| {
+                cidr.addr.is_ipv4() == want_v4
+                    && !matches!(scope, AddressScope::Link | AddressScope::Host)
+            })
+        };
+        if self.require_ipv4 && !has_routable(true) {
+            error!(
+                "❌ No non-link-local IPv4 address assigned to '{}'",
+                self.name
+            );
+            ok = false;
+        }
+        if self.require_ipv6 && !has_routable(false) {
+            error!(
+                "❌ No non-link-local IPv6 address assigned to '{}'",
+                self.name
+            );
+            ok = false;
+        }
+
+        ok
+    }
+
+    fn match_counters(&self, attrs: &[LinkAttribute]) -> bool {
+        let mut ok = true;
+
+        let limits = [
+            ("rx_errors", self.max_rx_errors),
+            ("tx_errors", self.max_tx_errors),
+            ("rx_dropped", self.max_rx_dropped),
+            ("tx_dropped", self.max_tx_dropped),
+        ];
+        if limits.iter().any(|(_, limit)| limit.is_some()) {
+            let stats = attrs.iter().find_map(|attr| match attr {
+                LinkAttribute::Stats64(stats) => Some(stats),
+                _ => None,
+            });
+            let Some(stats) = stats else {
+                error!("❌ Stats64 attribute not found for '{}'", self.name);
+                return false;
+            };
+            let actuals = [
+                stats.rx_errors,
+                stats.tx_errors,
+                stats.rx_dropped,
+                stats.tx_dropped,
+            ];
+            for ((counter, limit), actual) in limits.iter().zip(actuals) {
+                match limit {
+                    Some(limit) if actual > *limit => {
+                        error!(
+                            "❌ {} too high for '{}': {} exceeds limit {}",
+                            counter, self.name, actual, limit
+                        );
+                        ok = false;
+                    }
+                    Some(limit) => {
+                        debug!(
+                            "✅ {} within limit for '{}': {} <= {}",
+                            counter, self.name, actual, limit
+                        );
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        if let Some(limit) = self.max_carrier_changes {
+            let actual = attrs.iter().find_map(|attr| match attr {
+                LinkAttribute::CarrierChanges(val) => Some(*val),
+                _ => None,
+            });
+            match actual {
+                Some(changes) if changes > limit => {
+                    error!(
+                        "❌ carrier_changes too high for '{}': {} exceeds limit {}",
+                        self.name, changes, limit
+                    );
+                    ok = false;
+                }
+                Some(changes) => {
+                    debug!(
+                        "✅ carrier_changes within limit for '{}': {} <= {}",
+                        self.name, changes, limit
+                    );
+                }
+                None => {
+                    error!("❌ CarrierChanges attribute not found for '{}'", self.name);
+                    ok = false;
+                }
+            }
+        }
+
+        ok
+    }
+
     fn match_oper_state(&self, expected: &InterfaceState, attrs: &[LinkAttribute]) -> bool {
         use rtnetlink::packet_route::link::State::*;
         use LinkAttribute::OperState;
@@ -136,3 +615,276 @@ impl Interface {
         }
     }
 }
+
+/// Validates every interface and reports all failing ones by name in a single result.
+pub fn validate_interfaces(links: &LinkSnapshot, interfaces: &[Interface]) -> bool {
+    let failed: Vec<&str> = interfaces
+        .iter()
+        .filter(|interface| !interface.validate(links))
+        .map(|interface| interface.name.as_str())
+        .collect();
+
+    if failed.is_empty() {
+        info!("✅ All {} interfaces passed", interfaces.len());
+        true
+    } else {
+        error!(
+            "❌ {} of {} interfaces failed: {}",
+            failed.len(),
+            interfaces.len(),
+            failed.join(", ")
+        );
+        false
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use rtnetlink::packet_route::link::{InfoKind, State, Stats64};
+
+    pub(crate) fn link(index: u32, name: &str, attributes: Vec<LinkAttribute>) -> LinkMessage {
+        let mut message = LinkMessage::default();
+        message.header.index = index;
+        message
+            .attributes
+            .push(LinkAttribute::IfName(name.to_string()));
+        message.attributes.extend(attributes);
+        message
+    }
+
+    fn address(index: u32, cidr: &str, scope: AddressScope) -> AddressMessage {
+        let cidr: IpCidr = cidr.parse().unwrap();
+        let mut message = AddressMessage::default();
+        message.header.index = index;
+        message.header.prefix_len = cidr.prefix_len;
+        message.header.scope = scope;
+        message
+            .attributes
+            .push(AddressAttribute::Address(cidr.addr));
+        message
+    }
+
+    fn stats(rx_errors: u64, tx_dropped: u64) -> Stats64 {
+        let mut stats = Stats64::default();
+        stats.rx_errors = rx_errors;
+        stats.tx_dropped = tx_dropped;
+        stats
+    }
+
+    fn interface(name: &str) -> Interface {
+        Interface {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn snapshot() -> LinkSnapshot {
+        LinkSnapshot::new(
+            vec![
+                link(1, "lo", vec![LinkAttribute::OperState(State::Unknown)]),
+                link(
+                    2,
+                    "eth0",
+                    vec![
+                        LinkAttribute::OperState(State::Up),
+                        LinkAttribute::Mtu(9000),
+                        LinkAttribute::Promiscuity(0),
+                        LinkAttribute::Stats64(stats(3, 12)),
+                        LinkAttribute::CarrierChanges(2),
+                    ],
+                ),
+                link(3, "eth1", vec![LinkAttribute::OperState(State::Up)]),
+                link(
+                    4,
+                    "bond0",
+                    vec![
+                        LinkAttribute::Address(vec![0x52, 0x54, 0x00, 0x12, 0x34, 0x56]),
+                        LinkAttribute::LinkInfo(vec![LinkInfo::Kind(InfoKind::Bond)]),
+                    ],
+                ),
+            ],
+            vec![
+                address(1, "127.0.0.1/8", AddressScope::Host),
+                address(2, "192.168.1.10/24", AddressScope::Universe),
+                address(2, "fe80::1/64", AddressScope::Link),
+                address(3, "fd00::1/64", AddressScope::Universe),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_snapshot_lookup() {
+        let links = snapshot();
+        assert_eq!(links.by_name("eth0").map(|l| l.header.index), Some(2));
+        assert_eq!(links.by_index(1).and_then(link_name), Some("lo"));
+        assert!(links.by_name("eth2").is_none());
+        assert_eq!(links.addresses_of(2).count(), 2);
+    }
+
+    #[test]
+    fn test_validate_against_snapshot() {
+        let links = snapshot();
+
+        let eth0 = Interface {
+            state: Some(InterfaceState::Up),
+            mtu: Some(9000),
+            promisc: Some(false),
+            ..interface("eth0")
+        };
+        assert!(eth0.validate(&links));
+
+        let wrong_mtu = Interface {
+            mtu: Some(1500),
+            ..interface("eth0")
+        };
+        assert!(!wrong_mtu.validate(&links));
+
+        let absent = Interface {
+            state: Some(InterfaceState::Absent),
+            ..interface("eth2")
+        };
+        assert!(absent.validate(&links));
+        assert!(!interface("eth2").validate(&links));
+    }
+
+    #[test]
+    fn test_parse_ip_cidr() {
+        let v4: IpCidr = "192.168.1.10/24".parse().unwrap();
+        assert_eq!(v4.to_string(), "192.168.1.10/24");
+        let v6: IpCidr = "fd00::1/64".parse().unwrap();
+        assert_eq!(v6.prefix_len, 64);
+
+        assert!("192.168.1.10".parse::<IpCidr>().is_err());
+        assert!("192.168.1.10/33".parse::<IpCidr>().is_err());
+        assert!("not-an-ip/24".parse::<IpCidr>().is_err());
+    }
+
+    #[test]
+    fn test_ip_cidr_contains() {
+        let cidr = |s: &str| s.parse::<IpCidr>().unwrap();
+        assert!(cidr("10.0.0.0/8").contains(&cidr("10.42.0.0/16")));
+        assert!(cidr("0.0.0.0/0").contains(&cidr("192.168.1.0/24")));
+        assert!(!cidr("10.42.0.0/16").contains(&cidr("10.0.0.0/8")));
+        assert!(!cidr("10.0.0.0/8").contains(&cidr("11.0.0.0/16")));
+        assert!(cidr("::/0").contains(&cidr("fd00::/64")));
+        assert!(!cidr("0.0.0.0/0").contains(&cidr("fd00::/64")));
+    }
+
+    #[test]
+    fn test_validate_addresses() {
+        let links = snapshot();
+
+        let eth0 = Interface {
+            addresses: vec!["192.168.1.10/24".parse().unwrap()],
+            require_ipv4: true,
+            ..interface("eth0")
+        };
+        assert!(eth0.validate(&links));
+
+        let wrong_prefix = Interface {
+            addresses: vec!["192.168.1.10/16".parse().unwrap()],
+            ..interface("eth0")
+        };
+        assert!(!wrong_prefix.validate(&links));
+
+        // Only a link-local IPv6 address is present on eth0
+        let eth0_v6 = Interface {
+            require_ipv6: true,
+            ..interface("eth0")
+        };
+        assert!(!eth0_v6.validate(&links));
+
+        let eth1 = Interface {
+            addresses: vec!["fd00::1/64".parse().unwrap()],
+            require_ipv6: true,
+            ..interface("eth1")
+        };
+        assert!(eth1.validate(&links));
+        assert!(!Interface {
+            require_ipv4: true,
+            ..interface("eth1")
+        }
+        .validate(&links));
+    }
+
+    #[test]
+    fn test_parse_mac_pattern() {
+        let exact: MacPattern = "52:54:00:12:34:56".parse().unwrap();
+        assert!(!exact.is_oui());
+        assert!(exact.matches(&[0x52, 0x54, 0x00, 0x12, 0x34, 0x56]));
+        assert!(!exact.matches(&[0x52, 0x54, 0x00, 0x12, 0x34, 0x57]));
+
+        let oui: MacPattern = "52-54-00".parse().unwrap();
+        assert!(oui.is_oui());
+        assert!(oui.matches(&[0x52, 0x54, 0x00, 0xaa, 0xbb, 0xcc]));
+        assert_eq!(oui.to_string(), "52:54:00:*");
+
+        assert!("52:54".parse::<MacPattern>().is_err());
+        assert!("52:54:0g".parse::<MacPattern>().is_err());
+    }
+
+    #[test]
+    fn test_validate_mac_and_kind() {
+        let links = snapshot();
+
+        let bond0 = Interface {
+            mac: Some("52:54:00".parse().unwrap()),
+            kind: Some("bond".to_string()),
+            ..interface("bond0")
+        };
+        assert!(bond0.validate(&links));
+
+        let wrong_mac = Interface {
+            mac: Some("52:54:00:00:00:01".parse().unwrap()),
+            ..interface("bond0")
+        };
+        assert!(!wrong_mac.validate(&links));
+
+        let wrong_kind = Interface {
+            kind: Some("bridge".to_string()),
+            ..interface("bond0")
+        };
+        assert!(!wrong_kind.validate(&links));
+
+        // Physical devices carry no link kind
+        let eth0 = Interface {
+            kind: Some("vlan".to_string()),
+            ..interface("eth0")
+        };
+        assert!(!eth0.validate(&links));
+    }
+
+    #[test]
+    fn test_validate_counters() {
+        let links = snapshot();
+
+        let within = Interface {
+            max_rx_errors: Some(3),
+            max_tx_errors: Some(0),
+            max_tx_dropped: Some(20),
+            max_carrier_changes: Some(2),
+            ..interface("eth0")
+        };
+        assert!(within.validate(&links));
+
+        let too_many_errors = Interface {
+            max_rx_errors: Some(0),
+            ..interface("eth0")
+        };
+        assert!(!too_many_errors.validate(&links));
+
+        let flapping = Interface {
+            max_carrier_changes: Some(1),
+            ..interface("eth0")
+        };
+        assert!(!flapping.validate(&links));
+
+        // eth1 reports no statistics at all
+        let no_stats = Interface {
+            max_rx_dropped: Some(100),
+            ..interface("eth1")
+        };
+        assert!(!no_stats.validate(&links));
+    }
+}
@@ -0,0 +1,53 @@
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::{fmt, str::FromStr};
+
+/// A regular expression from the configuration, validated when the config is parsed.
+///
+/// Only the source is stored so checks holding a pattern stay `Eq + Hash`; call
+/// [`Pattern::regex`] once per check run rather than per line matched.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
+pub struct Pattern(String);
+
+impl Pattern {
+    pub fn regex(&self) -> Regex {
+        Regex::new(&self.0).expect("pattern was validated on parse")
+    }
+
+    /// Compiles the pattern with `^` and `$` anchoring to line boundaries.
+    pub fn multi_line_regex(&self) -> Regex {
+        RegexBuilder::new(&self.0)
+            .multi_line(true)
+            .build()
+            .expect("pattern was validated on parse")
+    }
+
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.regex().is_match(haystack)
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Regex::new(s)
+            .map(|_| Pattern(s.to_string()))
+            .map_err(|e| format!("Invalid regex '{}': {}", s, e))
+    }
+}
+
+impl TryFrom<String> for Pattern {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,279 @@
+use crate::checks::file::{id_matches, user_name};
+use crate::checks::pattern::Pattern;
+use crate::errors::GreenlightError;
+use regex::Regex;
+use std::fs::{read, read_dir, read_to_string};
+use std::path::Path;
+use tracing::{debug, error, info};
+
+const PROC: &str = "/proc";
+/// `comm` is truncated to TASK_COMM_LEN - 1 bytes.
+const COMM_LEN: usize = 15;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub comm: String,
+    /// Arguments joined with spaces.
+    pub cmdline: String,
+    /// Effective uid.
+    pub uid: u32,
+}
+
+/// Selects processes by `comm` name and/or command line.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessFilter<'a> {
+    pub name: Option<&'a str>,
+    pub cmdline_regex: Option<&'a Pattern>,
+    /// User name or numeric uid.
+    pub user: Option<&'a str>,
+}
+
+fn effective_uid(status: &str) -> Option<u32> {
+    // Uid:	real	effective	saved	fs
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+fn read_process(dir: &Path, pid: u32) -> Option<ProcessInfo> {
+    let comm = read_to_string(dir.join("comm")).ok()?;
+    let cmdline = read(dir.join("cmdline")).ok()?;
+    let uid = effective_uid(&read_to_string(dir.join("status")).ok()?)?;
+    let cmdline = cmdline
+        .split(|b| *b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(String::from_utf8_lossy)
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(ProcessInfo {
+        pid,
+        comm: comm.trim_end_matches('\n').to_string(),
+        cmdline,
+        uid,
+    })
+}
+
+/// Lists processes under a `/proc` root; processes that exit while scanning are skipped.
+pub fn scan_processes(proc_root: &Path) -> Result<Vec<ProcessInfo>, GreenlightError> {
+    let mut processes = Vec::new();
+    for entry in read_dir(proc_root)? {
+        let entry = entry?;
+        let Some(pid) = entry.file_name().to_str().and_then(|s| s.parse().ok()) else {
+            continue;
+        };
+        if let Some(process) = read_process(&entry.path(), pid) {
+            processes.push(process);
+        }
+    }
+    Ok(processes)
+}
+
+impl ProcessFilter<'_> {
+    /// `cmdline` is the compiled `cmdline_regex`, built once per scan.
+    fn matches(&self, process: &ProcessInfo, cmdline: Option<&Regex>) -> bool {
+        if let Some(name) = self.name {
+            let truncated: String = name.chars().take(COMM_LEN).collect();
+            if process.comm != truncated {
+                return false;
+            }
+        }
+        if let Some(regex) = cmdline {
+            if !regex.is_match(&process.cmdline) {
+                return false;
+            }
+        }
+        if let Some(user) = self.user {
+            if !id_matches(user, process.uid, &user_name(process.uid)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(name) = self.name {
+            parts.push(format!("name '{}'", name));
+        }
+        if let Some(pattern) = self.cmdline_regex {
+            parts.push(format!("cmdline /{}/", pattern));
+        }
+        if let Some(user) = self.user {
+            parts.push(format!("user {}", user));
+        }
+        parts.join(", ")
+    }
+}
+
+pub fn check_process_in(
+    processes: &[ProcessInfo],
+    filter: &ProcessFilter,
+    min_count: u32,
+    max_count: Option<u32>,
+) -> bool {
+    let cmdline = filter.cmdline_regex.map(Pattern::regex);
+    let matching: Vec<&ProcessInfo> = processes
+        .iter()
+        .filter(|p| filter.matches(p, cmdline.as_ref()))
+        .collect();
+    let count = matching.len() as u32;
+    for process in &matching {
+        debug!("Process {} matches: {}", process.pid, process.cmdline);
+    }
+
+    let mut ok = true;
+    if count < min_count {
+        error!(
+            "❌ {} process(es) with {}, expected at least {}",
+            count,
+            filter.describe(),
+            min_count
+        );
+        ok = false;
+    }
+    if let Some(max) = max_count {
+        if count > max {
+            let pids: Vec<String> = matching.iter().map(|p| p.pid.to_string()).collect();
+            error!(
+                "❌ {} process(es) with {}, expected at most {} (pids {})",
+                count,
+                filter.describe(),
+                max,
+                pids.join(", ")
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!("✅ {} process(es) with {}", count, filter.describe());
+    }
+    ok
+}
+
+pub fn check_process(
+    filter: &ProcessFilter,
+    min_count: u32,
+    max_count: Option<u32>,
+) -> Result<bool, GreenlightError> {
+    let processes = scan_processes(Path::new(PROC))?;
+    debug!("Scanned {} processes", processes.len());
+    Ok(check_process_in(&processes, filter, min_count, max_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    fn fake_process(root: &Path, pid: u32, comm: &str, args: &[&str], uid: u32) {
+        let dir = root.join(pid.to_string());
+        create_dir_all(&dir).unwrap();
+        write(dir.join("comm"), format!("{}\n", comm)).unwrap();
+        let mut cmdline = args.join("\0");
+        cmdline.push('\0');
+        write(dir.join("cmdline"), cmdline).unwrap();
+        write(
+            dir.join("status"),
+            format!("Name:\t{}\nUid:\t{uid}\t{uid}\t{uid}\t{uid}\n", comm),
+        )
+        .unwrap();
+    }
+
+    fn processes() -> Vec<ProcessInfo> {
+        let root = TempDir::new().unwrap();
+        fake_process(root.path(), 1, "systemd", &["/usr/lib/systemd/systemd"], 0);
+        fake_process(
+            root.path(),
+            812,
+            "ovs-vswitchd",
+            &["ovs-vswitchd", "unix:/var/run/openvswitch/db.sock"],
+            0,
+        );
+        fake_process(
+            root.path(),
+            900,
+            "conmon-monitor-",
+            &["/usr/bin/conmon-monitor-daemon", "--log-level=info"],
+            1000,
+        );
+        fake_process(root.path(), 901, "worker", &["worker", "--id=1"], 1000);
+        fake_process(root.path(), 902, "worker", &["worker", "--id=2"], 1000);
+        // Not a process directory.
+        create_dir_all(root.path().join("net")).unwrap();
+        scan_processes(root.path()).unwrap()
+    }
+
+    #[test]
+    fn test_scan_processes() {
+        let mut processes = processes();
+        processes.sort_by_key(|p| p.pid);
+        assert_eq!(processes.len(), 5);
+        assert_eq!(processes[1].comm, "ovs-vswitchd");
+        assert_eq!(
+            processes[1].cmdline,
+            "ovs-vswitchd unix:/var/run/openvswitch/db.sock"
+        );
+        assert_eq!(processes[2].uid, 1000);
+    }
+
+    #[test]
+    fn test_check_process_in() {
+        let processes = processes();
+        let by_name = |name| ProcessFilter {
+            name: Some(name),
+            ..Default::default()
+        };
+
+        assert!(check_process_in(
+            &processes,
+            &by_name("ovs-vswitchd"),
+            1,
+            Some(1)
+        ));
+        assert!(check_process_in(
+            &processes,
+            &by_name("conmon-monitor-daemon"),
+            1,
+            None
+        ));
+        assert!(!check_process_in(
+            &processes,
+            &by_name("ovsdb-server"),
+            1,
+            None
+        ));
+        assert!(check_process_in(
+            &processes,
+            &by_name("ovsdb-server"),
+            0,
+            Some(0)
+        ));
+        assert!(check_process_in(&processes, &by_name("worker"), 2, Some(2)));
+        assert!(!check_process_in(
+            &processes,
+            &by_name("worker"),
+            1,
+            Some(1)
+        ));
+
+        let pattern: Pattern = r"--id=\d+".parse().unwrap();
+        let as_root = ProcessFilter {
+            cmdline_regex: Some(&pattern),
+            user: Some("0"),
+            ..Default::default()
+        };
+        assert!(!check_process_in(&processes, &as_root, 1, None));
+        let as_user = ProcessFilter {
+            user: Some("1000"),
+            ..as_root
+        };
+        assert!(check_process_in(&processes, &as_user, 2, None));
+    }
+}
@@ -1,22 +1,179 @@
 use std::path::Path;
-use tracing::debug;
+use tracing::{debug, error, info, warn};
 
 use nix::sys::statvfs::{statvfs, FsFlags};
+use nix::unistd::{access, AccessFlags};
 
 use crate::errors::GreenlightError;
 
-pub fn is_rootfs_readonly() -> Result<bool, GreenlightError> {
-    match statvfs(Path::new("/")) {
+/// Returns whether the filesystem containing `path` is mounted read-only.
+pub fn is_mount_readonly(path: &Path) -> Result<bool, GreenlightError> {
+    match statvfs(path) {
         Ok(state) => {
-            debug!("Rootfs flags: {:?}", state.flags());
-            let is_readonly = state.flags().contains(FsFlags::ST_RDONLY);
-            debug!("Is rootfs readonly? {}", is_readonly);
-            Ok(is_readonly)
+            debug!("{} flags: {:?}", path.display(), state.flags());
+            Ok(state.flags().contains(FsFlags::ST_RDONLY))
         }
         Err(error) => Err(GreenlightError::CheckFailed(error.to_string())),
     }
 }
 
+pub fn is_rootfs_readonly() -> Result<bool, GreenlightError> {
+    let is_readonly = is_mount_readonly(Path::new("/"))?;
+    debug!("Is rootfs readonly? {}", is_readonly);
+    Ok(is_readonly)
+}
+
+/// Checks that `path` is (or is not) writable, using both the mount flags and an `access(W_OK)` probe.
+///
+/// A path counts as writable only if its filesystem is mounted read-write *and* the
+/// current process is allowed to write to it.
+pub fn check_path_writable(path: &Path, writable: bool) -> Result<bool, GreenlightError> {
+    if !path.exists() {
+        error!("❌ '{}' does not exist", path.display());
+        return Ok(false);
+    }
+
+    let readonly_mount = is_mount_readonly(path)?;
+    let access_result = access(path, AccessFlags::W_OK);
+    debug!(
+        "'{}': read-only mount = {}, access(W_OK) = {:?}",
+        path.display(),
+        readonly_mount,
+        access_result
+    );
+
+    let is_writable = !readonly_mount && access_result.is_ok();
+    if is_writable == writable {
+        info!(
+            "✅ '{}' is {}",
+            path.display(),
+            if writable { "writable" } else { "read-only" }
+        );
+        return Ok(true);
+    }
+
+    if writable {
+        if readonly_mount {
+            error!("❌ '{}' is on a read-only mount", path.display());
+        }
+        if let Err(errno) = access_result {
+            error!("❌ '{}' is not writable: {}", path.display(), errno);
+        }
+    } else {
+        error!(
+            "❌ '{}' is writable, expected read-only (mount is read-write, access(W_OK) succeeded)",
+            path.display()
+        );
+    }
+    Ok(false)
+}
+
+/// Capacity figures for a filesystem, as seen by an unprivileged process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FsUsage {
+    pub total_bytes: u64,
+    pub free_bytes: u64,
+    pub total_inodes: u64,
+    pub free_inodes: u64,
+}
+
+impl FsUsage {
+    pub fn of(path: &Path) -> Result<Self, GreenlightError> {
+        let state = statvfs(path).map_err(|e| GreenlightError::CheckFailed(e.to_string()))?;
+        let fragment_size = state.fragment_size() as u64;
+        Ok(FsUsage {
+            total_bytes: state.blocks() as u64 * fragment_size,
+            free_bytes: state.blocks_available() as u64 * fragment_size,
+            total_inodes: state.files() as u64,
+            free_inodes: state.files_available() as u64,
+        })
+    }
+
+    pub fn free_percent(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 0.0;
+        }
+        self.free_bytes as f64 * 100.0 / self.total_bytes as f64
+    }
+}
+
+/// Minimum free space a filesystem must have; unset thresholds are not checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskThresholds {
+    pub min_free_bytes: Option<u64>,
+    pub min_free_percent: Option<u8>,
+    pub min_free_inodes: Option<u64>,
+}
+
+/// Compares `usage` against every threshold and reports each one that is not met.
+pub fn check_disk_usage(path: &Path, usage: &FsUsage, thresholds: &DiskThresholds) -> bool {
+    debug!("{} usage: {:?}", path.display(), usage);
+    let mut ok = true;
+
+    if let Some(min) = thresholds.min_free_bytes {
+        if usage.free_bytes < min {
+            error!(
+                "❌ '{}' has {} bytes free, below minimum {}",
+                path.display(),
+                usage.free_bytes,
+                min
+            );
+            ok = false;
+        }
+    }
+
+    if let Some(min) = thresholds.min_free_percent {
+        let free = usage.free_percent();
+        if free < f64::from(min) {
+            error!(
+                "❌ '{}' is {:.1}% free, below minimum {}%",
+                path.display(),
+                free,
+                min
+            );
+            ok = false;
+        }
+    }
+
+    if let Some(min) = thresholds.min_free_inodes {
+        if usage.total_inodes == 0 {
+            // btrfs and some virtual filesystems do not track inode counts
+            warn!(
+                "'{}' does not report inode counts, skipping inode threshold",
+                path.display()
+            );
+        } else if usage.free_inodes < min {
+            error!(
+                "❌ '{}' has {} inodes free, below minimum {}",
+                path.display(),
+                usage.free_inodes,
+                min
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!(
+            "✅ '{}' has enough free space ({} bytes, {:.1}%, {} inodes)",
+            path.display(),
+            usage.free_bytes,
+            usage.free_percent(),
+            usage.free_inodes
+        );
+    }
+    ok
+}
+
+pub fn check_disk_space(path: &Path, thresholds: &DiskThresholds) -> Result<bool, GreenlightError> {
+    if !path.exists() {
+        error!("❌ '{}' does not exist", path.display());
+        return Ok(false);
+    }
+    let usage = FsUsage::of(path)?;
+    Ok(check_disk_usage(path, &usage, thresholds))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,4 +188,58 @@ mod tests {
             "Expected a Result, got something unexpected"
         );
     }
+
+    #[test]
+    fn test_check_path_writable() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(check_path_writable(dir.path(), true).unwrap());
+        assert!(!check_path_writable(dir.path(), false).unwrap());
+        assert!(!check_path_writable(&dir.path().join("missing"), true).unwrap());
+    }
+
+    #[test]
+    fn test_check_disk_usage() {
+        let path = Path::new("/var");
+        let usage = FsUsage {
+            total_bytes: 10_000,
+            free_bytes: 1_500,
+            total_inodes: 1_000,
+            free_inodes: 50,
+        };
+
+        let ok = DiskThresholds {
+            min_free_bytes: Some(1_000),
+            min_free_percent: Some(15),
+            min_free_inodes: Some(50),
+        };
+        assert!(check_disk_usage(path, &usage, &ok));
+
+        let percent = DiskThresholds {
+            min_free_percent: Some(20),
+            ..Default::default()
+        };
+        assert!(!check_disk_usage(path, &usage, &percent));
+
+        let inodes = DiskThresholds {
+            min_free_inodes: Some(100),
+            ..Default::default()
+        };
+        assert!(!check_disk_usage(path, &usage, &inodes));
+
+        let untracked = FsUsage {
+            total_inodes: 0,
+            free_inodes: 0,
+            ..usage
+        };
+        assert!(check_disk_usage(path, &untracked, &inodes));
+    }
+
+    #[test]
+    fn test_check_disk_space_runs() {
+        let thresholds = DiskThresholds {
+            min_free_bytes: Some(1),
+            ..Default::default()
+        };
+        assert!(check_disk_space(Path::new("/"), &thresholds).is_ok());
+    }
 }
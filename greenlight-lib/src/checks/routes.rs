@@ -0,0 +1,350 @@
+use crate::checks::network::{link_name, IpCidr, LinkSnapshot, Netlink};
+use crate::errors::GreenlightError;
+use rtnetlink::packet_route::{
+    route::{RouteAddress, RouteAttribute, RouteMessage, RouteType},
+    AddressFamily,
+};
+use rtnetlink::IpVersion;
+use serde::Deserialize;
+use std::{fmt, net::IpAddr, str::FromStr};
+use tracing::{debug, error, info};
+
+/// Destination of an expected route: `default` or an explicit prefix.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(try_from = "String")]
+pub enum RouteDestination {
+    Default,
+    Prefix(IpCidr),
+}
+
+impl FromStr for RouteDestination {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(RouteDestination::Default),
+            prefix => prefix.parse().map(RouteDestination::Prefix),
+        }
+    }
+}
+
+impl TryFrom<String> for RouteDestination {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for RouteDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteDestination::Default => f.write_str("default"),
+            RouteDestination::Prefix(prefix) => write!(f, "{}", prefix),
+        }
+    }
+}
+
+/// A route that must be present, optionally pinned to a gateway, interface, table or metric.
+#[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
+pub struct Route {
+    pub destination: RouteDestination,
+    #[serde(default)]
+    pub gateway: Option<IpAddr>,
+    #[serde(default)]
+    pub interface: Option<String>,
+    #[serde(default)]
+    pub table: Option<u32>,
+    #[serde(default)]
+    pub metric: Option<u32>,
+}
+
+/// A unicast route from the kernel, with its output interface resolved to a name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteEntry {
+    pub destination: IpCidr,
+    pub gateway: Option<IpAddr>,
+    pub interface: Option<String>,
+    pub table: u32,
+    pub metric: Option<u32>,
+}
+
+impl RouteEntry {
+    /// Converts a netlink route; returns `None` for non-unicast or non-IP routes.
+    pub fn from_message(message: &RouteMessage, links: &LinkSnapshot) -> Option<Self> {
+        if message.header.kind != RouteType::Unicast {
+            return None;
+        }
+
+        let to_ip = |address: &RouteAddress| match address {
+            RouteAddress::Inet(ip) => Some(IpAddr::V4(*ip)),
+            RouteAddress::Inet6(ip) => Some(IpAddr::V6(*ip)),
+            _ => None,
+        };
+
+        let mut destination = None;
+        let mut gateway = None;
+        let mut oif = None;
+        let mut table = u32::from(message.header.table);
+        let mut metric = None;
+        for attr in &message.attributes {
+            match attr {
+                RouteAttribute::Destination(address) => destination = to_ip(address),
+                RouteAttribute::Gateway(address) => gateway = to_ip(address),
+                RouteAttribute::Oif(index) => oif = Some(*index),
+                RouteAttribute::Table(id) => table = *id,
+                RouteAttribute::Priority(priority) => metric = Some(*priority),
+                _ => {}
+            }
+        }
+
+        // Default routes carry no RTA_DST, only a zero prefix length.
+        let addr = match destination {
+            Some(addr) => addr,
+            None if message.header.destination_prefix_length == 0 => {
+                match message.header.address_family {
+                    AddressFamily::Inet => IpAddr::from([0u8; 4]),
+                    AddressFamily::Inet6 => IpAddr::from([0u8; 16]),
+                    _ => return None,
+                }
+            }
+            None => return None,
+        };
+
+        Some(RouteEntry {
+            destination: IpCidr {
+                addr,
+                prefix_len: message.header.destination_prefix_length,
+            },
+            gateway,
+            interface: oif
+                .and_then(|index| links.by_index(index))
+                .and_then(link_name)
+                .map(str::to_string),
+            table,
+            metric,
+        })
+    }
+}
+
+impl fmt::Display for RouteEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.destination)?;
+        if let Some(gateway) = &self.gateway {
+            write!(f, " via {}", gateway)?;
+        }
+        if let Some(interface) = &self.interface {
+            write!(f, " dev {}", interface)?;
+        }
+        write!(f, " table {}", self.table)?;
+        if let Some(metric) = &self.metric {
+            write!(f, " metric {}", metric)?;
+        }
+        Ok(())
+    }
+}
+
+impl Route {
+    fn matches_destination(&self, entry: &RouteEntry) -> bool {
+        match &self.destination {
+            RouteDestination::Default => entry.destination.prefix_len == 0,
+            RouteDestination::Prefix(prefix) => entry.destination == *prefix,
+        }
+    }
+
+    /// Attributes of `entry` that disagree with this expectation.
+    fn mismatches(&self, entry: &RouteEntry) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if let Some(gateway) = &self.gateway {
+            if entry.gateway.as_ref() != Some(gateway) {
+                mismatches.push(format!("gateway {}", gateway));
+            }
+        }
+        if let Some(interface) = &self.interface {
+            if entry.interface.as_ref() != Some(interface) {
+                mismatches.push(format!("interface {}", interface));
+            }
+        }
+        if let Some(table) = self.table {
+            if entry.table != table {
+                mismatches.push(format!("table {}", table));
+            }
+        }
+        if let Some(metric) = self.metric {
+            if entry.metric != Some(metric) {
+                mismatches.push(format!("metric {}", metric));
+            }
+        }
+        mismatches
+    }
+
+    pub fn validate(&self, routes: &[RouteEntry]) -> bool {
+        let candidates: Vec<&RouteEntry> = routes
+            .iter()
+            .filter(|entry| self.matches_destination(entry))
+            .collect();
+
+        if candidates.is_empty() {
+            error!("❌ No route to {}", self.destination);
+            return false;
+        }
+
+        if let Some(entry) = candidates
+            .iter()
+            .find(|entry| self.mismatches(entry).is_empty())
+        {
+            debug!("✅ Route {} found: {}", self.destination, entry);
+            return true;
+        }
+
+        for entry in candidates {
+            error!(
+                "❌ Route {} does not match expected {}: found {}",
+                self.destination,
+                self.mismatches(entry).join(", "),
+                entry
+            );
+        }
+        false
+    }
+}
+
+/// Returns true if some route (including a default route) covers `prefix`.
+pub fn is_routable(prefix: &IpCidr, routes: &[RouteEntry]) -> bool {
+    match routes
+        .iter()
+        .filter(|entry| entry.destination.contains(prefix))
+        .max_by_key(|entry| entry.destination.prefix_len)
+    {
+        Some(entry) => {
+            debug!("✅ {} is routable via {}", prefix, entry);
+            true
+        }
+        None => {
+            error!("❌ {} is not routable", prefix);
+            false
+        }
+    }
+}
+
+pub fn check_routes(routes: &[RouteEntry], expected: &[Route], routable: &[IpCidr]) -> bool {
+    let failed_routes = expected
+        .iter()
+        .filter(|route| !route.validate(routes))
+        .count();
+    let failed_prefixes = routable
+        .iter()
+        .filter(|prefix| !is_routable(prefix, routes))
+        .count();
+
+    if failed_routes + failed_prefixes == 0 {
+        info!("✅ All route expectations met");
+        true
+    } else {
+        error!(
+            "❌ {} route(s) and {} routable prefix(es) failed",
+            failed_routes, failed_prefixes
+        );
+        false
+    }
+}
+
+pub async fn validate_routes(
+    netlink: &Netlink,
+    links: &LinkSnapshot,
+    expected: &[Route],
+    routable: &[IpCidr],
+) -> Result<bool, GreenlightError> {
+    let mut messages = netlink.routes(IpVersion::V4).await?;
+    messages.extend(netlink.routes(IpVersion::V6).await?);
+
+    let routes: Vec<RouteEntry> = messages
+        .iter()
+        .filter_map(|message| RouteEntry::from_message(message, links))
+        .collect();
+    debug!("Collected {} unicast routes", routes.len());
+
+    Ok(check_routes(&routes, expected, routable))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(destination: &str, gateway: Option<&str>, interface: &str, metric: u32) -> RouteEntry {
+        RouteEntry {
+            destination: destination.parse().unwrap(),
+            gateway: gateway.map(|gw| gw.parse().unwrap()),
+            interface: Some(interface.to_string()),
+            table: 254,
+            metric: Some(metric),
+        }
+    }
+
+    fn routes() -> Vec<RouteEntry> {
+        vec![
+            entry("0.0.0.0/0", Some("192.168.1.1"), "eth0", 100),
+            entry("192.168.1.0/24", None, "eth0", 100),
+            entry("10.42.0.0/16", None, "cni0", 0),
+        ]
+    }
+
+    fn route(destination: &str) -> Route {
+        Route {
+            destination: destination.parse().unwrap(),
+            gateway: None,
+            interface: None,
+            table: None,
+            metric: None,
+        }
+    }
+
+    #[test]
+    fn test_default_route() {
+        let routes = routes();
+        assert!(route("default").validate(&routes));
+
+        let via_gateway = Route {
+            gateway: Some("192.168.1.1".parse().unwrap()),
+            interface: Some("eth0".to_string()),
+            table: Some(254),
+            ..route("default")
+        };
+        assert!(via_gateway.validate(&routes));
+
+        let wrong_gateway = Route {
+            gateway: Some("192.168.1.254".parse().unwrap()),
+            ..route("default")
+        };
+        assert!(!wrong_gateway.validate(&routes));
+
+        assert!(!route("default").validate(&routes[1..]));
+    }
+
+    #[test]
+    fn test_prefix_route() {
+        let routes = routes();
+        let cni = Route {
+            interface: Some("cni0".to_string()),
+            metric: Some(0),
+            ..route("10.42.0.0/16")
+        };
+        assert!(cni.validate(&routes));
+        assert!(!Route {
+            metric: Some(100),
+            ..cni
+        }
+        .validate(&routes));
+        assert!(!route("10.43.0.0/16").validate(&routes));
+    }
+
+    #[test]
+    fn test_routable_prefixes() {
+        let routes = routes();
+        let cidr = |s: &str| s.parse::<IpCidr>().unwrap();
+        assert!(is_routable(&cidr("10.42.3.0/24"), &routes));
+        assert!(is_routable(&cidr("8.8.8.8/32"), &routes));
+        assert!(!is_routable(&cidr("8.8.8.8/32"), &routes[1..]));
+        assert!(!is_routable(&cidr("fd00::/64"), &routes));
+    }
+}
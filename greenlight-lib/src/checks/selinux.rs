@@ -0,0 +1,305 @@
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::Path;
+use tokio::process::Command;
+use tracing::{debug, error, info};
+
+const SELINUXFS: &str = "/sys/fs/selinux";
+const SELINUX_CONFIG: &str = "/etc/selinux/config";
+/// When auditd is running the kernel hands AVC records to it instead of the kernel log.
+const AUDIT_LOG: &str = "/var/log/audit/audit.log";
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SelinuxMode {
+    Enforcing,
+    Permissive,
+    Disabled,
+}
+
+impl fmt::Display for SelinuxMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SelinuxMode::Enforcing => "enforcing",
+            SelinuxMode::Permissive => "permissive",
+            SelinuxMode::Disabled => "disabled",
+        })
+    }
+}
+
+/// Current mode from selinuxfs; an unmounted selinuxfs means SELinux is disabled.
+pub fn read_mode(selinuxfs: &Path) -> Result<SelinuxMode, GreenlightError> {
+    let enforce = selinuxfs.join("enforce");
+    if !enforce.is_file() {
+        return Ok(SelinuxMode::Disabled);
+    }
+    match read_to_string(&enforce)?.trim() {
+        "1" => Ok(SelinuxMode::Enforcing),
+        "0" => Ok(SelinuxMode::Permissive),
+        other => Err(GreenlightError::CheckFailed(format!(
+            "Unexpected value in {}: {}",
+            enforce.display(),
+            other
+        ))),
+    }
+}
+
+/// Policy name (`SELINUXTYPE`) from `/etc/selinux/config`; selinuxfs does not expose it.
+pub fn policy_name(config: &str) -> Option<String> {
+    config.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("SELINUXTYPE=")?;
+        Some(value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// AVC denial lines from the log, paired with the type of their source context.
+pub fn parse_avc_denials(log: &str) -> Vec<(String, &str)> {
+    log.lines()
+        .filter(|line| line.contains("avc:") && line.contains("denied"))
+        .filter_map(|line| {
+            // scontext=system_u:system_r:httpd_t:s0
+            let scontext = line
+                .split_whitespace()
+                .find_map(|field| field.strip_prefix("scontext="))?;
+            let domain = scontext.split(':').nth(2)?;
+            Some((domain.to_string(), line))
+        })
+        .collect()
+}
+
+/// Validates the SELinux mode and policy under the given selinuxfs root and config file.
+pub fn check_selinux_state(
+    selinuxfs: &Path,
+    config: &Path,
+    mode: SelinuxMode,
+    policy: Option<&str>,
+) -> Result<bool, GreenlightError> {
+    let actual = read_mode(selinuxfs)?;
+    let loaded_policy = if config.is_file() {
+        policy_name(&read_to_string(config)?)
+    } else {
+        None
+    };
+    debug!("SELinux is {} with policy {:?}", actual, loaded_policy);
+
+    let mut ok = true;
+    if actual != mode {
+        error!(
+            "❌ SELinux mode mismatch: expected {}, got {}",
+            mode, actual
+        );
+        ok = false;
+    }
+    if let Some(expected) = policy {
+        if loaded_policy.as_deref() != Some(expected) {
+            error!(
+                "❌ SELinux policy mismatch: expected {}, got {}",
+                expected,
+                loaded_policy.as_deref().unwrap_or("none")
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!(
+            "✅ SELinux is {} ({})",
+            actual,
+            loaded_policy.as_deref().unwrap_or("no policy")
+        );
+    }
+    Ok(ok)
+}
+
+/// Returns false if `log` contains AVC denials for any of `domains`.
+pub fn check_avc_denials(log: &str, domains: &[String]) -> bool {
+    let mut ok = true;
+    for (domain, line) in parse_avc_denials(log) {
+        if domains.contains(&domain) {
+            error!("❌ AVC denial for {}: {}", domain, line);
+            ok = false;
+        }
+    }
+    ok
+}
+
+/// Whether `log` holds any audit record at all, AVC or not.
+///
+/// A boot always produces some (e.g. `SERVICE_START`), so none means the source
+/// does not receive audit messages and an empty AVC result would be meaningless.
+pub fn has_audit_records(log: &str) -> bool {
+    log.lines().any(|line| line.contains("audit("))
+}
+
+/// AVC records logged by auditd since boot.
+async fn ausearch_avc() -> Result<String, GreenlightError> {
+    // --input-logs reads the configured log files even when stdin is not a terminal.
+    let output = Command::new("ausearch")
+        .args(["--input-logs", "-m", "AVC,USER_AVC", "-ts", "boot"])
+        .output()
+        .await
+        .map_err(GreenlightError::Io)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else if stderr.contains("<no matches>") {
+        Ok(String::new())
+    } else {
+        Err(GreenlightError::CheckFailed(format!(
+            "ausearch exited with {}: {}",
+            output.status,
+            stderr.trim()
+        )))
+    }
+}
+
+/// Kernel and audit messages journald recorded since boot.
+async fn journal_audit_log() -> Result<String, GreenlightError> {
+    let output = Command::new("journalctl")
+        .args([
+            "--boot",
+            "--output=cat",
+            "--no-pager",
+            "--quiet",
+            "_TRANSPORT=audit",
+            "_TRANSPORT=kernel",
+        ])
+        .output()
+        .await
+        .map_err(GreenlightError::Io)?;
+
+    if !output.status.success() {
+        return Err(GreenlightError::CheckFailed(format!(
+            "journalctl exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let log = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !has_audit_records(&log) {
+        return Err(GreenlightError::CheckFailed(
+            "No audit records in the journal since boot, cannot check AVC denials".to_string(),
+        ));
+    }
+    Ok(log)
+}
+
+/// AVC records since boot, from auditd when it is logging and from the journal otherwise.
+async fn boot_audit_log() -> Result<String, GreenlightError> {
+    let auditd = tokio::task::spawn_blocking(|| Path::new(AUDIT_LOG).is_file()).await?;
+    debug!(
+        "Reading AVC records from {}",
+        if auditd { "auditd" } else { "the journal" }
+    );
+    if auditd {
+        ausearch_avc().await
+    } else {
+        journal_audit_log().await
+    }
+}
+
+pub async fn check_selinux(
+    mode: SelinuxMode,
+    policy: Option<&str>,
+    avc_domains: &[String],
+) -> Result<bool, GreenlightError> {
+    let expected_policy = policy.map(str::to_string);
+    let mut ok = tokio::task::spawn_blocking(move || {
+        check_selinux_state(
+            Path::new(SELINUXFS),
+            Path::new(SELINUX_CONFIG),
+            mode,
+            expected_policy.as_deref(),
+        )
+    })
+    .await??;
+
+    if !avc_domains.is_empty() {
+        let log = boot_audit_log().await?;
+        if check_avc_denials(&log, avc_domains) {
+            info!(
+                "✅ No AVC denials since boot for {}",
+                avc_domains.join(", ")
+            );
+        } else {
+            ok = false;
+        }
+    }
+    Ok(ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    const CONFIG: &str = "\
+# This file controls the state of SELinux on the system.
+SELINUX=enforcing
+SELINUXTYPE=targeted
+";
+
+    const LOG: &str = "\
+audit: type=1400 audit(1718000000.123:42): avc:  denied  { read } for  pid=812 comm=\"microshift\" name=\"kubelet\" dev=\"vda4\" ino=1234 scontext=system_u:system_r:kubelet_t:s0 tcontext=system_u:object_r:var_lib_t:s0 tclass=dir permissive=0
+audit: type=1400 audit(1718000001.456:43): avc:  granted  { setenforce } for  pid=1 comm=\"systemd\" scontext=system_u:system_r:init_t:s0 tcontext=system_u:object_r:security_t:s0 tclass=security
+audit: type=1130 audit(1718000002.789:44): pid=1 uid=0 msg='unit=greenboot-healthcheck comm=\"systemd\" res=success'
+";
+
+    #[test]
+    fn test_read_mode() {
+        let root = TempDir::new().unwrap();
+        assert_eq!(read_mode(root.path()).unwrap(), SelinuxMode::Disabled);
+        write(root.path().join("enforce"), "1").unwrap();
+        assert_eq!(read_mode(root.path()).unwrap(), SelinuxMode::Enforcing);
+        write(root.path().join("enforce"), "0").unwrap();
+        assert_eq!(read_mode(root.path()).unwrap(), SelinuxMode::Permissive);
+    }
+
+    #[test]
+    fn test_check_selinux_state() {
+        let root = TempDir::new().unwrap();
+        write(root.path().join("enforce"), "1").unwrap();
+        let config = root.path().join("config");
+        write(&config, CONFIG).unwrap();
+
+        assert!(check_selinux_state(
+            root.path(),
+            &config,
+            SelinuxMode::Enforcing,
+            Some("targeted")
+        )
+        .unwrap());
+        assert!(!check_selinux_state(root.path(), &config, SelinuxMode::Permissive, None).unwrap());
+        assert!(
+            !check_selinux_state(root.path(), &config, SelinuxMode::Enforcing, Some("mls"))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_avc_denials() {
+        let denials = parse_avc_denials(LOG);
+        assert_eq!(denials.len(), 1);
+        assert_eq!(denials[0].0, "kubelet_t");
+
+        let domains = |d: &[&str]| d.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert!(!check_avc_denials(
+            LOG,
+            &domains(&["kubelet_t", "container_t"])
+        ));
+        assert!(check_avc_denials(LOG, &domains(&["init_t", "container_t"])));
+    }
+
+    #[test]
+    fn test_has_audit_records() {
+        assert!(has_audit_records(LOG));
+        assert!(!has_audit_records(
+            "kernel: Linux version 5.14.0\nkernel: Command line: ro\n"
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::checks::network::{format_mac, MacPattern, Netlink};
+use crate::checks::sysfs::read_value;
+use crate::errors::GreenlightError;
+use rtnetlink::packet_route::link::{LinkAttribute, LinkMessage, VfInfo};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+/// VF MAC addresses from the PF's `IFLA_VFINFO_LIST`, keyed by VF number.
+///
+/// The PF driver reports these whichever driver the VF is bound to, so VFs handed to
+/// vfio-pci or DPDK, which have no netdev under `virtfn<N>/net`, are covered too.
+pub fn vf_macs(pf: &LinkMessage) -> BTreeMap<u32, Vec<u8>> {
+    pf.attributes
+        .iter()
+        .filter_map(|attr| match attr {
+            LinkAttribute::VfInfoList(vfs) => Some(vfs),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|vf| {
+            vf.0.iter().find_map(|info| match info {
+                // IFLA_VF_MAC is zero-padded to 32 bytes.
+                VfInfo::Mac(mac) => Some((mac.vf_id, mac.mac[..6].to_vec())),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+/// Validates the SR-IOV configuration of `interface` under the given `/sys/class/net` root,
+/// comparing `macs` against the VF MACs reported by the PF driver.
+pub fn check_sriov_vfs_in(
+    sys_class_net: &Path,
+    interface: &str,
+    count: u32,
+    macs: &[MacPattern],
+    vf_macs: &BTreeMap<u32, Vec<u8>>,
+) -> Result<bool, GreenlightError> {
+    let device = sys_class_net.join(interface).join("device");
+    let numvfs_path = device.join("sriov_numvfs");
+    if !numvfs_path.is_file() {
+        error!(
+            "❌ '{}' does not support SR-IOV: {} not found",
+            interface,
+            numvfs_path.display()
+        );
+        return Ok(false);
+    }
+
+    let numvfs: u32 = read_value(&numvfs_path)?;
+    let totalvfs: u32 = read_value(&device.join("sriov_totalvfs"))?;
+    debug!("'{}' has {} of {} VFs enabled", interface, numvfs, totalvfs);
+
+    if count > totalvfs {
+        error!(
+            "❌ '{}' supports at most {} VFs, but {} are expected",
+            interface, totalvfs, count
+        );
+        return Ok(false);
+    }
+    if numvfs != count {
+        error!(
+            "❌ VF count mismatch for '{}': expected {}, got {} (max {})",
+            interface, count, numvfs, totalvfs
+        );
+        return Ok(false);
+    }
+
+    let mut ok = true;
+    for (vf, expected) in macs.iter().enumerate() {
+        match vf_macs.get(&(vf as u32)) {
+            Some(mac) if expected.matches(mac) => {
+                debug!(
+                    "✅ VF {} of '{}' has MAC {}",
+                    vf,
+                    interface,
+                    format_mac(mac)
+                );
+            }
+            Some(mac) => {
+                error!(
+                    "❌ VF {} of '{}' MAC mismatch: expected {}, got {}",
+                    vf,
+                    interface,
+                    expected,
+                    format_mac(mac)
+                );
+                ok = false;
+            }
+            None => {
+                error!(
+                    "❌ VF {} of '{}' is not reported by the PF driver",
+                    vf, interface
+                );
+                ok = false;
+            }
+        }
+    }
+
+    if ok {
+        info!("✅ '{}' has {} VFs provisioned", interface, numvfs);
+    }
+    Ok(ok)
+}
+
+pub async fn check_sriov_vfs(
+    netlink: &Netlink,
+    interface: &str,
+    count: u32,
+    macs: &[MacPattern],
+) -> Result<bool, GreenlightError> {
+    let vf_macs = if macs.is_empty() {
+        BTreeMap::new()
+    } else {
+        netlink
+            .link_with_vfs(interface)
+            .await?
+            .map(|pf| vf_macs(&pf))
+            .unwrap_or_default()
+    };
+    check_sriov_vfs_in(Path::new(SYS_CLASS_NET), interface, count, macs, &vf_macs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::network::tests::link;
+    use rtnetlink::packet_route::link::{LinkVfInfo, VfInfoMac};
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    fn fake_pf(numvfs: u32, totalvfs: u32) -> TempDir {
+        let root = TempDir::new().unwrap();
+        let device = root.path().join("ens1f0").join("device");
+        create_dir_all(&device).unwrap();
+        write(device.join("sriov_numvfs"), format!("{}\n", numvfs)).unwrap();
+        write(device.join("sriov_totalvfs"), format!("{}\n", totalvfs)).unwrap();
+        root
+    }
+
+    fn pf(vf_macs: &[[u8; 6]]) -> LinkMessage {
+        let vfs = vf_macs
+            .iter()
+            .enumerate()
+            .map(|(vf, mac)| LinkVfInfo(vec![VfInfo::Mac(VfInfoMac::new(vf as u32, mac))]))
+            .collect();
+        link(2, "ens1f0", vec![LinkAttribute::VfInfoList(vfs)])
+    }
+
+    #[test]
+    fn test_vf_count() {
+        let root = fake_pf(4, 8);
+        let none = BTreeMap::new();
+        assert!(check_sriov_vfs_in(root.path(), "ens1f0", 4, &[], &none).unwrap());
+        assert!(!check_sriov_vfs_in(root.path(), "ens1f0", 2, &[], &none).unwrap());
+        assert!(!check_sriov_vfs_in(root.path(), "ens1f0", 16, &[], &none).unwrap());
+        assert!(!check_sriov_vfs_in(root.path(), "eth0", 4, &[], &none).unwrap());
+    }
+
+    #[test]
+    fn test_vf_macs() {
+        let root = fake_pf(2, 8);
+        let vf_macs = vf_macs(&pf(&[[2, 0, 0, 0, 0, 1], [2, 0, 0, 0, 0, 2]]));
+        assert_eq!(vf_macs[&1], [2, 0, 0, 0, 0, 2]);
+
+        let macs: Vec<MacPattern> = ["02:00:00:00:00:01", "02:00:00"]
+            .iter()
+            .map(|mac| mac.parse().unwrap())
+            .collect();
+        assert!(check_sriov_vfs_in(root.path(), "ens1f0", 2, &macs, &vf_macs).unwrap());
+
+        let wrong: Vec<MacPattern> = vec!["02:00:00:00:00:02".parse().unwrap()];
+        assert!(!check_sriov_vfs_in(root.path(), "ens1f0", 2, &wrong, &vf_macs).unwrap());
+        assert!(!check_sriov_vfs_in(root.path(), "ens1f0", 2, &macs, &BTreeMap::new()).unwrap());
+    }
+}
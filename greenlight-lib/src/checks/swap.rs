@@ -0,0 +1,210 @@
+use crate::checks::mount::unescape;
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::fmt;
+use std::fs::read_to_string;
+use tracing::{debug, error, info};
+
+pub const PROC_SWAPS: &str = "/proc/swaps";
+
+/// Kind of swap backing a `/proc/swaps` row.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SwapKind {
+    /// Compressed RAM (`/dev/zramN`), reported by the kernel as a partition.
+    Zram,
+    /// A block device other than zram.
+    Partition,
+    File,
+}
+
+impl fmt::Display for SwapKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SwapKind::Zram => "zram",
+            SwapKind::Partition => "partition",
+            SwapKind::File => "file",
+        })
+    }
+}
+
+/// One row of `/proc/swaps`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapEntry {
+    pub filename: String,
+    pub kind: SwapKind,
+    pub size_kb: u64,
+    pub used_kb: u64,
+    pub priority: i32,
+}
+
+fn parse_line(line: &str) -> Option<SwapEntry> {
+    // Filename Type Size Used Priority
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [filename, kind, size, used, priority] = fields[..] else {
+        return None;
+    };
+    let filename = unescape(filename);
+    let kind = match kind {
+        "file" => SwapKind::File,
+        _ if filename.starts_with("/dev/zram") => SwapKind::Zram,
+        _ => SwapKind::Partition,
+    };
+    Some(SwapEntry {
+        filename,
+        kind,
+        size_kb: size.parse().ok()?,
+        used_kb: used.parse().ok()?,
+        priority: priority.parse().ok()?,
+    })
+}
+
+/// Parses every active swap area; the header line is skipped.
+///
+/// A row that does not parse is an error rather than skipped, so an unexpected
+/// format never reads as "no swap".
+pub fn parse_swaps(content: &str) -> Result<Vec<SwapEntry>, GreenlightError> {
+    content
+        .lines()
+        .skip(1)
+        .map(|line| {
+            parse_line(line).ok_or_else(|| {
+                GreenlightError::CheckFailed(format!("Unexpected {} row: {:?}", PROC_SWAPS, line))
+            })
+        })
+        .collect()
+}
+
+/// Validates active swap areas against the policy.
+///
+/// `allowed` of `None` permits any kind; `disabled` requires no swap at all.
+pub fn check_swap_entries(
+    entries: &[SwapEntry],
+    allowed: Option<&[SwapKind]>,
+    max_total_mb: Option<u64>,
+    disabled: bool,
+) -> bool {
+    for entry in entries {
+        debug!(
+            "Swap {} ({}): {} kB, {} kB used, priority {}",
+            entry.filename, entry.kind, entry.size_kb, entry.used_kb, entry.priority
+        );
+    }
+
+    if disabled {
+        if entries.is_empty() {
+            info!("✅ Swap is disabled");
+            return true;
+        }
+        for entry in entries {
+            error!(
+                "❌ Swap is enabled on {} ({}), expected none",
+                entry.filename, entry.kind
+            );
+        }
+        return false;
+    }
+
+    let mut ok = true;
+    if let Some(allowed) = allowed {
+        for entry in entries.iter().filter(|e| !allowed.contains(&e.kind)) {
+            let allowed: Vec<String> = allowed.iter().map(ToString::to_string).collect();
+            error!(
+                "❌ Swap on {} is {}, allowed kinds: {}",
+                entry.filename,
+                entry.kind,
+                if allowed.is_empty() {
+                    "none".to_string()
+                } else {
+                    allowed.join(", ")
+                }
+            );
+            ok = false;
+        }
+    }
+
+    let total_mb = entries.iter().map(|e| e.size_kb).sum::<u64>() / 1024;
+    if let Some(max) = max_total_mb {
+        if total_mb > max {
+            error!(
+                "❌ {} MB of swap configured, above maximum {} MB",
+                total_mb, max
+            );
+            ok = false;
+        }
+    }
+
+    if ok {
+        info!(
+            "✅ Swap matches policy ({} area(s), {} MB)",
+            entries.len(),
+            total_mb
+        );
+    }
+    ok
+}
+
+pub fn check_swap(
+    allowed: Option<&[SwapKind]>,
+    max_total_mb: Option<u64>,
+    disabled: bool,
+) -> Result<bool, GreenlightError> {
+    let entries = parse_swaps(&read_to_string(PROC_SWAPS)?)?;
+    Ok(check_swap_entries(
+        &entries,
+        allowed,
+        max_total_mb,
+        disabled,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SWAPS: &str = "\
+Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority
+/dev/zram0                              partition\t3979260\t\t1024\t\t100
+/var/swap\\040file                       file\t\t1048572\t\t0\t\t-2
+";
+
+    const EMPTY: &str = "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n";
+
+    #[test]
+    fn test_parse_swaps() {
+        let entries = parse_swaps(SWAPS).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].kind, SwapKind::Zram);
+        assert_eq!(entries[0].size_kb, 3979260);
+        assert_eq!(entries[0].priority, 100);
+        assert_eq!(entries[1].filename, "/var/swap file");
+        assert_eq!(entries[1].kind, SwapKind::File);
+        assert_eq!(entries[1].priority, -2);
+        assert!(parse_swaps(EMPTY).unwrap().is_empty());
+        assert!(parse_swaps(&format!("{}/dev/sda2 partition\n", EMPTY)).is_err());
+    }
+
+    #[test]
+    fn test_check_swap_entries() {
+        let entries = parse_swaps(SWAPS).unwrap();
+        let zram_only = entries[..1].to_vec();
+
+        assert!(check_swap_entries(
+            &zram_only,
+            Some(&[SwapKind::Zram]),
+            Some(4096),
+            false
+        ));
+        assert!(!check_swap_entries(
+            &entries,
+            Some(&[SwapKind::Zram]),
+            None,
+            false
+        ));
+        assert!(check_swap_entries(&entries, None, None, false));
+        assert!(!check_swap_entries(&zram_only, None, Some(1024), false));
+        assert!(!check_swap_entries(&zram_only, Some(&[]), None, false));
+        assert!(!check_swap_entries(&zram_only, None, None, true));
+        assert!(check_swap_entries(&[], Some(&[]), Some(0), true));
+    }
+}
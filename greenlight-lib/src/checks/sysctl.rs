@@ -0,0 +1,224 @@
+use crate::errors::GreenlightError;
+use serde::Deserialize;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::{fmt, str::FromStr};
+use tracing::{debug, error, info};
+
+const PROC_SYS: &str = "/proc/sys";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+impl Comparison {
+    fn holds(self, actual: i128, bound: i128) -> bool {
+        match self {
+            Comparison::Ge => actual >= bound,
+            Comparison::Gt => actual > bound,
+            Comparison::Le => actual <= bound,
+            Comparison::Lt => actual < bound,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Ge => ">=",
+            Comparison::Gt => ">",
+            Comparison::Le => "<=",
+            Comparison::Lt => "<",
+        }
+    }
+}
+
+/// Expected sysctl value: an exact value, compared field by field so list-valued keys
+/// like `net.ipv4.ip_local_port_range` ignore tab/space differences, or a numeric bound
+/// such as `">=1"`. Bounds are `i128` so both signed and unsigned 64-bit sysctls
+/// (e.g. `kernel.shmmax`) compare correctly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "RawSysctlValue")]
+pub enum SysctlValue {
+    Exact(Vec<String>),
+    Numeric(Comparison, i128),
+}
+
+/// Lets TOML configs write either `expected = 1` or `expected = ">= 1"`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawSysctlValue {
+    Integer(i64),
+    String(String),
+}
+
+impl FromStr for SysctlValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let comparison = [
+            (">=", Comparison::Ge),
+            ("<=", Comparison::Le),
+            (">", Comparison::Gt),
+            ("<", Comparison::Lt),
+        ]
+        .into_iter()
+        .find_map(|(prefix, cmp)| s.strip_prefix(prefix).map(|rest| (cmp, rest)));
+
+        match comparison {
+            Some((cmp, bound)) => bound
+                .trim()
+                .parse()
+                .map(|bound| SysctlValue::Numeric(cmp, bound))
+                .map_err(|_| format!("Invalid numeric bound in '{}'", s)),
+            None => Ok(SysctlValue::Exact(
+                s.split_whitespace().map(str::to_string).collect(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<RawSysctlValue> for SysctlValue {
+    type Error = String;
+
+    fn try_from(value: RawSysctlValue) -> Result<Self, Self::Error> {
+        match value {
+            RawSysctlValue::Integer(n) => Ok(SysctlValue::Exact(vec![n.to_string()])),
+            RawSysctlValue::String(s) => s.parse(),
+        }
+    }
+}
+
+impl fmt::Display for SysctlValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SysctlValue::Exact(fields) => f.write_str(&fields.join(" ")),
+            SysctlValue::Numeric(cmp, bound) => write!(f, "{} {}", cmp.symbol(), bound),
+        }
+    }
+}
+
+impl SysctlValue {
+    pub fn matches(&self, actual: &str) -> bool {
+        match self {
+            SysctlValue::Exact(fields) => actual.split_whitespace().eq(fields.iter()),
+            SysctlValue::Numeric(cmp, bound) => actual
+                .trim()
+                .parse()
+                .is_ok_and(|actual| cmp.holds(actual, *bound)),
+        }
+    }
+}
+
+/// Translates a sysctl name to its path under `root`, the way `sysctl(8)` does.
+///
+/// Dots separate components and a `/` inside a dotted name stands for a literal dot,
+/// so `net.ipv4.conf.eth0/100.rp_filter` refers to the `eth0.100` VLAN. Names whose
+/// first separator is `/` are taken as paths as-is.
+pub fn sysctl_path(root: &Path, key: &str) -> PathBuf {
+    let first_separator = key.find(['.', '/']).map(|i| &key[i..=i]);
+    if first_separator == Some("/") {
+        return root.join(key.trim_start_matches('/'));
+    }
+    key.split('.').fold(root.to_path_buf(), |path, part| {
+        path.join(part.replace('/', "."))
+    })
+}
+
+pub fn check_sysctl_in(
+    root: &Path,
+    key: &str,
+    expected: &SysctlValue,
+) -> Result<bool, GreenlightError> {
+    let path = sysctl_path(root, key);
+    if !path.is_file() {
+        error!("❌ sysctl '{}' does not exist ({})", key, path.display());
+        return Ok(false);
+    }
+
+    let actual = read_to_string(&path)?;
+    let actual = actual.trim();
+    debug!("sysctl {} = {}", key, actual);
+
+    if expected.matches(actual) {
+        info!("✅ sysctl {} = {}", key, actual);
+        Ok(true)
+    } else {
+        error!(
+            "❌ sysctl '{}' mismatch: expected {}, got {}",
+            key, expected, actual
+        );
+        Ok(false)
+    }
+}
+
+pub fn check_sysctl(key: &str, expected: &SysctlValue) -> Result<bool, GreenlightError> {
+    check_sysctl_in(Path::new(PROC_SYS), key, expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+    use tempfile::TempDir;
+
+    fn value(s: &str) -> SysctlValue {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_sysctl_path() {
+        let root = Path::new("/proc/sys");
+        assert_eq!(
+            sysctl_path(root, "net.ipv4.ip_forward"),
+            Path::new("/proc/sys/net/ipv4/ip_forward")
+        );
+        assert_eq!(
+            sysctl_path(root, "net.ipv4.conf.eth0/100.rp_filter"),
+            Path::new("/proc/sys/net/ipv4/conf/eth0.100/rp_filter")
+        );
+        assert_eq!(
+            sysctl_path(root, "net/ipv4/conf/eth0.100/rp_filter"),
+            Path::new("/proc/sys/net/ipv4/conf/eth0.100/rp_filter")
+        );
+    }
+
+    #[test]
+    fn test_sysctl_value() {
+        assert!(value("1").matches("1\n"));
+        assert!(!value("1").matches("2"));
+        assert!(value("32768 60999").matches("32768\t60999"));
+        assert!(!value("32768 60999").matches("32768\t61000"));
+        assert!(value(">= 10").matches("10"));
+        assert!(value(">0").matches("10"));
+        assert!(!value("<10").matches("10"));
+        assert!(value("<=-1").matches("-1"));
+        assert!(!value(">=1").matches("abc"));
+        // kernel.shmmax defaults to ULONG_MAX - 2^24.
+        assert!(value(">=1").matches("18446744073692774399"));
+        assert!(value("<=18446744073709551615").matches("18446744073692774399"));
+        assert!(">=x".parse::<SysctlValue>().is_err());
+    }
+
+    #[test]
+    fn test_check_sysctl_in() {
+        let root = TempDir::new().unwrap();
+        let ipv4 = root.path().join("net").join("ipv4");
+        create_dir_all(&ipv4).unwrap();
+        write(ipv4.join("ip_forward"), "1\n").unwrap();
+        write(ipv4.join("ip_local_port_range"), "32768\t60999\n").unwrap();
+
+        assert!(check_sysctl_in(root.path(), "net.ipv4.ip_forward", &value("1")).unwrap());
+        assert!(!check_sysctl_in(root.path(), "net.ipv4.ip_forward", &value("0")).unwrap());
+        assert!(check_sysctl_in(
+            root.path(),
+            "net.ipv4.ip_local_port_range",
+            &value("32768 60999")
+        )
+        .unwrap());
+        assert!(!check_sysctl_in(root.path(), "kernel.panic", &value(">=1")).unwrap());
+    }
+}
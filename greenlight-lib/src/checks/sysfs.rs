@@ -0,0 +1,17 @@
+use crate::errors::GreenlightError;
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Reads a single-value sysfs/procfs attribute such as `sriov_numvfs` or `nr_hugepages`.
+pub(crate) fn read_value<T>(path: &Path) -> Result<T, GreenlightError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let content = read_to_string(path)?;
+    content.trim().parse().map_err(|e| {
+        GreenlightError::CheckFailed(format!("Invalid value in {}: {}", path.display(), e))
+    })
+}
@@ -0,0 +1,365 @@
+use crate::checks::network::{link_kind, link_name, LinkSnapshot};
+use rtnetlink::packet_route::link::{
+    InfoBond, InfoData, InfoVlan, LinkAttribute, LinkInfo, LinkMessage,
+};
+use serde::Deserialize;
+use tracing::{debug, error, info};
+
+/// A bond and the ports that must be enslaved to it.
+#[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
+pub struct Bond {
+    pub name: String,
+    /// Kernel bonding mode name, e.g. `802.3ad` or `active-backup`.
+    #[serde(default)]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+/// A bridge and the ports that must be attached to it.
+#[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
+pub struct Bridge {
+    pub name: String,
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+/// A VLAN sub-interface, its parent link and its VLAN ID.
+#[derive(Debug, Deserialize, Clone, PartialEq, Hash, Eq)]
+pub struct Vlan {
+    pub name: String,
+    pub parent: String,
+    pub id: u16,
+}
+
+/// Expected master/slave relationships between links.
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Hash, Eq)]
+pub struct Topology {
+    #[serde(default)]
+    pub bonds: Vec<Bond>,
+    #[serde(default)]
+    pub bridges: Vec<Bridge>,
+    #[serde(default)]
+    pub vlans: Vec<Vlan>,
+}
+
+impl Topology {
+    pub fn is_empty(&self) -> bool {
+        self.bonds.is_empty() && self.bridges.is_empty() && self.vlans.is_empty()
+    }
+}
+
+fn controller_of(link: &LinkMessage) -> Option<u32> {
+    link.attributes.iter().find_map(|attr| match attr {
+        LinkAttribute::Controller(index) => Some(*index),
+        _ => None,
+    })
+}
+
+fn parent_of(link: &LinkMessage) -> Option<u32> {
+    link.attributes.iter().find_map(|attr| match attr {
+        LinkAttribute::Link(index) => Some(*index),
+        _ => None,
+    })
+}
+
+fn info_data(link: &LinkMessage) -> Option<&InfoData> {
+    link.attributes.iter().find_map(|attr| match attr {
+        LinkAttribute::LinkInfo(infos) => infos.iter().find_map(|info| match info {
+            LinkInfo::Data(data) => Some(data),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
+fn bond_mode(link: &LinkMessage) -> Option<String> {
+    match info_data(link)? {
+        InfoData::Bond(infos) => infos.iter().find_map(|info| match info {
+            InfoBond::Mode(mode) => Some(mode.to_string()),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+fn vlan_id(link: &LinkMessage) -> Option<u16> {
+    match info_data(link)? {
+        InfoData::Vlan(infos) => infos.iter().find_map(|info| match info {
+            InfoVlan::Id(id) => Some(*id),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Looks up `name` and checks its link kind, logging why it failed otherwise.
+fn find_link_of_kind<'a>(
+    links: &'a LinkSnapshot,
+    name: &str,
+    kind: &str,
+) -> Option<&'a LinkMessage> {
+    let Some(link) = links.by_name(name) else {
+        error!("❌ {} '{}' not found", kind, name);
+        return None;
+    };
+    match link_kind(link) {
+        Some(actual) if actual == kind => Some(link),
+        actual => {
+            error!(
+                "❌ '{}' is not a {}: link kind is {}",
+                name,
+                kind,
+                actual.as_deref().unwrap_or("none")
+            );
+            None
+        }
+    }
+}
+
+/// Checks that every member is enslaved to `master`, reporting each stray member.
+fn check_members(
+    links: &LinkSnapshot,
+    master: &LinkMessage,
+    master_name: &str,
+    members: &[String],
+) -> bool {
+    let mut ok = true;
+    for member in members {
+        let Some(link) = links.by_name(member) else {
+            error!("❌ '{}' member '{}' not found", master_name, member);
+            ok = false;
+            continue;
+        };
+        match controller_of(link) {
+            Some(index) if index == master.header.index => {
+                debug!("✅ '{}' is enslaved to '{}'", member, master_name);
+            }
+            Some(index) => {
+                error!(
+                    "❌ '{}' is enslaved to '{}', expected '{}'",
+                    member,
+                    links
+                        .by_index(index)
+                        .and_then(link_name)
+                        .unwrap_or("<unknown>"),
+                    master_name
+                );
+                ok = false;
+            }
+            None => {
+                error!("❌ '{}' is not enslaved to '{}'", member, master_name);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+impl Bond {
+    pub fn validate(&self, links: &LinkSnapshot) -> bool {
+        let Some(bond) = find_link_of_kind(links, &self.name, "bond") else {
+            return false;
+        };
+        let mut ok = check_members(links, bond, &self.name, &self.members);
+
+        if let Some(expected_mode) = &self.mode {
+            match bond_mode(bond) {
+                Some(mode) if mode == *expected_mode => {
+                    debug!("✅ Bond '{}' is in {} mode", self.name, mode);
+                }
+                mode => {
+                    error!(
+                        "❌ Bond '{}' mode mismatch: expected {}, got {}",
+                        self.name,
+                        expected_mode,
+                        mode.as_deref().unwrap_or("unknown")
+                    );
+                    ok = false;
+                }
+            }
+        }
+        ok
+    }
+}
+
+impl Bridge {
+    pub fn validate(&self, links: &LinkSnapshot) -> bool {
+        let Some(bridge) = find_link_of_kind(links, &self.name, "bridge") else {
+            return false;
+        };
+        check_members(links, bridge, &self.name, &self.members)
+    }
+}
+
+impl Vlan {
+    pub fn validate(&self, links: &LinkSnapshot) -> bool {
+        let Some(vlan) = find_link_of_kind(links, &self.name, "vlan") else {
+            return false;
+        };
+        let mut ok = true;
+
+        let parent = parent_of(vlan)
+            .and_then(|index| links.by_index(index))
+            .and_then(link_name);
+        if parent != Some(self.parent.as_str()) {
+            error!(
+                "❌ VLAN '{}' parent mismatch: expected {}, got {}",
+                self.name,
+                self.parent,
+                parent.unwrap_or("none")
+            );
+            ok = false;
+        }
+
+        match vlan_id(vlan) {
+            Some(id) if id == self.id => debug!("✅ VLAN '{}' has ID {}", self.name, id),
+            id => {
+                error!(
+                    "❌ VLAN '{}' ID mismatch: expected {}, got {:?}",
+                    self.name, self.id, id
+                );
+                ok = false;
+            }
+        }
+        ok
+    }
+}
+
+impl Topology {
+    /// Validates every bond, bridge and VLAN against one snapshot, reporting all failures.
+    pub fn validate(&self, links: &LinkSnapshot) -> bool {
+        let bonds = self
+            .bonds
+            .iter()
+            .filter(|bond| !bond.validate(links))
+            .count();
+        let bridges = self
+            .bridges
+            .iter()
+            .filter(|bridge| !bridge.validate(links))
+            .count();
+        let vlans = self
+            .vlans
+            .iter()
+            .filter(|vlan| !vlan.validate(links))
+            .count();
+
+        if bonds + bridges + vlans == 0 {
+            info!("✅ Network topology matches");
+            true
+        } else {
+            error!(
+                "❌ Network topology mismatch: {} bond(s), {} bridge(s), {} VLAN(s) failed",
+                bonds, bridges, vlans
+            );
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::network::tests::link;
+    use rtnetlink::packet_route::link::{BondMode, InfoKind};
+
+    fn snapshot() -> LinkSnapshot {
+        LinkSnapshot::new(
+            vec![
+                link(2, "eth0", vec![LinkAttribute::Controller(10)]),
+                link(3, "eth1", vec![LinkAttribute::Controller(10)]),
+                link(4, "ens1f0", vec![LinkAttribute::Controller(11)]),
+                link(5, "eth2", vec![]),
+                link(
+                    10,
+                    "bond0",
+                    vec![LinkAttribute::LinkInfo(vec![
+                        LinkInfo::Kind(InfoKind::Bond),
+                        LinkInfo::Data(InfoData::Bond(vec![InfoBond::Mode(BondMode::Ieee8023Ad)])),
+                    ])],
+                ),
+                link(
+                    11,
+                    "br-ex",
+                    vec![LinkAttribute::LinkInfo(vec![LinkInfo::Kind(
+                        InfoKind::Bridge,
+                    )])],
+                ),
+                link(
+                    12,
+                    "vlan100",
+                    vec![
+                        LinkAttribute::Link(2),
+                        LinkAttribute::LinkInfo(vec![
+                            LinkInfo::Kind(InfoKind::Vlan),
+                            LinkInfo::Data(InfoData::Vlan(vec![InfoVlan::Id(100)])),
+                        ]),
+                    ],
+                ),
+            ],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_bond_topology() {
+        let links = snapshot();
+        let bond = Bond {
+            name: "bond0".to_string(),
+            mode: Some("802.3ad".to_string()),
+            members: vec!["eth0".to_string(), "eth1".to_string()],
+        };
+        assert!(bond.validate(&links));
+
+        let wrong_mode = Bond {
+            mode: Some("active-backup".to_string()),
+            ..bond.clone()
+        };
+        assert!(!wrong_mode.validate(&links));
+
+        let stray_member = Bond {
+            members: vec!["eth0".to_string(), "eth2".to_string()],
+            ..bond.clone()
+        };
+        assert!(!stray_member.validate(&links));
+
+        let not_a_bond = Bond {
+            name: "br-ex".to_string(),
+            mode: None,
+            members: vec![],
+        };
+        assert!(!not_a_bond.validate(&links));
+    }
+
+    #[test]
+    fn test_bridge_and_vlan_topology() {
+        let links = snapshot();
+        let topology = Topology {
+            bonds: vec![],
+            bridges: vec![Bridge {
+                name: "br-ex".to_string(),
+                members: vec!["ens1f0".to_string()],
+            }],
+            vlans: vec![Vlan {
+                name: "vlan100".to_string(),
+                parent: "eth0".to_string(),
+                id: 100,
+            }],
+        };
+        assert!(topology.validate(&links));
+
+        let wrong_bridge = Bridge {
+            name: "br-ex".to_string(),
+            members: vec!["eth0".to_string()],
+        };
+        assert!(!wrong_bridge.validate(&links));
+
+        let wrong_vlan = Vlan {
+            name: "vlan100".to_string(),
+            parent: "eth1".to_string(),
+            id: 200,
+        };
+        assert!(!wrong_vlan.validate(&links));
+    }
+}
@@ -39,13 +39,32 @@ impl Config {
         let content = std::fs::read_to_string(path).map_err(GreenlightError::Io)?;
         Self::from_str(&content)
     }
+
+    /// Rejects invalid checks and checks that are not supported on the configured target.
+    pub fn validate(&self) -> Result<(), GreenlightError> {
+        let target = self.system.target();
+        for check in self.required.checks.iter().chain(&self.wanted.checks) {
+            check.validate()?;
+            if let Some(targets) = check.supported_targets() {
+                if !targets.contains(target) {
+                    return Err(GreenlightError::InvalidConfig(format!(
+                        "{:?} is only supported on {:?} targets, not {:?}",
+                        check, targets, target
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl FromStr for Config {
     type Err = GreenlightError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        from_str(s).map_err(GreenlightError::ConfigParse)
+        let config: Config = from_str(s).map_err(GreenlightError::ConfigParse)?;
+        config.validate()?;
+        Ok(config)
     }
 }
 
@@ -6,6 +6,12 @@ pub enum GreenlightError {
     #[error("Failed to parse configuration: {0}")]
     ConfigParse(#[from] toml::de::Error),
 
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Failed to parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
     #[error("I/O error: {0}")]
     Io(#[from] io::Error),
 
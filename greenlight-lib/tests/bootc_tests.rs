@@ -0,0 +1,68 @@
+use greenlight_lib::checks::bootc::{compare, verify, BootcHost, Mismatch, OsRelease};
+
+const STATUS: &str = include_str!("fixtures/bootc-status.json");
+
+const OS_RELEASE: &str = r#"
+NAME="Red Hat Enterprise Linux"
+VERSION="9.5 (Plow)"
+ID="rhel"
+VERSION_ID="9.5"
+IMAGE_VERSION='9.20250429.0'
+OSTREE_VERSION='9.20250429.0'
+"#;
+
+#[test]
+fn test_parse_bootc_status_fixture() {
+    let host = BootcHost::from_json(STATUS).expect("Failed to parse fixture");
+    let image = host.booted_image().expect("Fixture has a booted image");
+
+    assert_eq!(image.image.image, "registry.redhat.io/rhel9/rhel-bootc:9.5");
+    assert_eq!(image.image.transport, "registry");
+    assert_eq!(image.version.as_deref(), Some("9.20250429.0"));
+    assert!(image.image_digest.starts_with("sha256:"));
+}
+
+#[test]
+fn test_bootc_status_matches_os_release() {
+    assert!(verify(STATUS, OS_RELEASE).unwrap());
+}
+
+#[test]
+fn test_bootc_status_reports_each_mismatch() {
+    let host = BootcHost::from_json(STATUS).unwrap();
+    let os_release = OsRelease::parse(
+        r#"
+VERSION_ID="10.0"
+IMAGE_VERSION="9.20250101.0"
+"#,
+    );
+
+    let mismatches = compare(host.booted_image().unwrap(), &os_release);
+    assert_eq!(
+        mismatches,
+        vec![
+            Mismatch {
+                field: "IMAGE_VERSION",
+                bootc: "9.20250429.0".to_string(),
+                os_release: "9.20250101.0".to_string(),
+            },
+            Mismatch {
+                field: "VERSION_ID",
+                bootc: "9.20250429.0".to_string(),
+                os_release: "10.0".to_string(),
+            },
+        ]
+    );
+    assert!(!verify(STATUS, "VERSION_ID=\"10.0\"").unwrap());
+}
+
+#[test]
+fn test_bootc_status_without_booted_image_fails() {
+    let status = r#"{"status": {"booted": null}}"#;
+    assert!(!verify(status, OS_RELEASE).unwrap());
+}
+
+#[test]
+fn test_invalid_bootc_status_json_is_an_error() {
+    assert!(verify("not json", OS_RELEASE).is_err());
+}
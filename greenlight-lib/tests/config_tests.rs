@@ -1,5 +1,12 @@
 use greenlight_lib::checks::check::Check;
+use greenlight_lib::checks::config_value::{ConfigFormat, ConfigScalar};
+use greenlight_lib::checks::contents::ContentPattern;
+use greenlight_lib::checks::memory::HugePages;
+use greenlight_lib::checks::selinux::SelinuxMode;
+use greenlight_lib::checks::swap::SwapKind;
 use greenlight_lib::config::*;
+use greenlight_lib::errors::GreenlightError;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[test]
@@ -102,7 +109,10 @@ fn test_parse_check_kinds() {
 
     assert_eq!(
         config.required.checks,
-        vec![Check::RootfsReadonly, Check::MicroshiftInstalled]
+        vec![
+            Check::RootfsReadonly,
+            Check::MicroshiftInstalled { version: None }
+        ]
     );
     assert_eq!(config.wanted.checks, vec![Check::SwapDisabled]);
 }
@@ -122,3 +132,1012 @@ fn test_invalid_check_kind() {
     let result: Result<Config, _> = toml::from_str(toml);
     assert!(result.is_err(), "Invalid check kind should fail");
 }
+
+#[test]
+fn test_parse_microshift_version_constraint() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "microshift_installed"
+        version = ">=4.18, <4.19"
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    match &config.required.checks[..] {
+        [Check::MicroshiftInstalled { version: Some(req) }] => {
+            assert!(req.matches(&semver::Version::new(4, 18, 3)));
+            assert!(!req.matches(&semver::Version::new(4, 19, 0)));
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_microshift_version_constraint() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "microshift_installed"
+        version = "not-a-range"
+    "#;
+
+    let result: Result<Config, _> = toml::from_str(toml);
+    assert!(result.is_err(), "Invalid version range should fail");
+}
+
+#[test]
+fn test_parse_interfaces_check() {
+    use greenlight_lib::checks::network::InterfaceState;
+
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "aarch64"
+        target = "dpu"
+
+        [[required.checks]]
+        type = "interfaces"
+
+        [[required.checks.interfaces]]
+        name = "eth0"
+        state = "up"
+        mtu = 9000
+
+        [[required.checks.interfaces]]
+        name = "eth1"
+        state = "absent"
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    match &config.required.checks[..] {
+        [Check::Interfaces { interfaces }] => {
+            assert_eq!(interfaces.len(), 2);
+            assert_eq!(interfaces[0].name, "eth0");
+            assert_eq!(interfaces[0].state, Some(InterfaceState::Up));
+            assert_eq!(interfaces[0].mtu, Some(9000));
+            assert_eq!(interfaces[0].promisc, None);
+            assert_eq!(interfaces[1].name, "eth1");
+            assert_eq!(interfaces[1].state, Some(InterfaceState::Absent));
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_interface_addresses() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "interfaces"
+
+        [[required.checks.interfaces]]
+        name = "eth0"
+        addresses = ["192.168.1.10/24", "fd00::1/64"]
+        require_ipv4 = true
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    match &config.required.checks[..] {
+        [Check::Interfaces { interfaces }] => {
+            let addresses: Vec<String> = interfaces[0]
+                .addresses
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            assert_eq!(addresses, vec!["192.168.1.10/24", "fd00::1/64"]);
+            assert!(interfaces[0].require_ipv4);
+            assert!(!interfaces[0].require_ipv6);
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+
+    let invalid = toml.replace("192.168.1.10/24", "192.168.1.10");
+    assert!(toml::from_str::<Config>(&invalid).is_err());
+}
+
+#[test]
+fn test_parse_interface_mac_and_kind() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "interfaces"
+
+        [[required.checks.interfaces]]
+        name = "can0"
+        kind = "can"
+
+        [[required.checks.interfaces]]
+        name = "ens1f0"
+        mac = "B8:CE:F6"
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    match &config.required.checks[..] {
+        [Check::Interfaces { interfaces }] => {
+            assert_eq!(interfaces[0].kind.as_deref(), Some("can"));
+            let mac = interfaces[1].mac.as_ref().expect("mac should parse");
+            assert!(mac.is_oui());
+            assert!(mac.matches(&[0xb8, 0xce, 0xf6, 0x01, 0x02, 0x03]));
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+
+    let invalid = toml.replace("B8:CE:F6", "B8:CE");
+    assert!(toml::from_str::<Config>(&invalid).is_err());
+}
+
+#[test]
+fn test_parse_net_topology_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "net_topology"
+
+        [[required.checks.bonds]]
+        name = "bond0"
+        mode = "802.3ad"
+        members = ["eth0", "eth1"]
+
+        [[required.checks.bridges]]
+        name = "br-ex"
+        members = ["ens1f0"]
+
+        [[required.checks.vlans]]
+        name = "vlan100"
+        parent = "eth0"
+        id = 100
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    match &config.required.checks[..] {
+        [Check::NetTopology { topology }] => {
+            assert_eq!(topology.bonds[0].name, "bond0");
+            assert_eq!(topology.bonds[0].mode.as_deref(), Some("802.3ad"));
+            assert_eq!(topology.bonds[0].members, vec!["eth0", "eth1"]);
+            assert_eq!(topology.bridges[0].members, vec!["ens1f0"]);
+            assert_eq!(topology.vlans[0].parent, "eth0");
+            assert_eq!(topology.vlans[0].id, 100);
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+}
+
+#[test]
+fn test_net_topology_rejects_empty() {
+    // `bond` instead of `bonds` would otherwise leave the topology empty.
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "net_topology"
+
+        [[required.checks.bond]]
+        name = "bond0"
+    "#;
+
+    assert!(matches!(
+        toml.parse::<Config>(),
+        Err(GreenlightError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_parse_routes_check() {
+    use greenlight_lib::checks::routes::RouteDestination;
+
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "routes"
+        routable = ["10.0.0.0/8"]
+
+        [[required.checks.routes]]
+        destination = "default"
+        gateway = "192.168.1.1"
+        interface = "eth0"
+
+        [[required.checks.routes]]
+        destination = "10.42.0.0/16"
+        table = 254
+        metric = 100
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    match &config.required.checks[..] {
+        [Check::Routes { routes, routable }] => {
+            assert_eq!(routes[0].destination, RouteDestination::Default);
+            assert_eq!(routes[0].gateway, Some("192.168.1.1".parse().unwrap()));
+            assert_eq!(routes[0].interface.as_deref(), Some("eth0"));
+            assert_eq!(routes[1].destination.to_string(), "10.42.0.0/16");
+            assert_eq!(routes[1].table, Some(254));
+            assert_eq!(routes[1].metric, Some(100));
+            assert_eq!(routable[0].to_string(), "10.0.0.0/8");
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+}
+
+/// Asserts that a config holding only `check` (a TOML table body) fails validation.
+fn assert_invalid_check(check: &str) {
+    let toml = format!(
+        r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        {}
+    "#,
+        check
+    );
+    assert!(
+        matches!(
+            toml.parse::<Config>(),
+            Err(GreenlightError::InvalidConfig(_))
+        ),
+        "expected {:?} to be rejected",
+        check
+    );
+}
+
+#[test]
+fn test_routes_check_requires_expectation() {
+    assert_invalid_check(r#"type = "routes""#);
+    // `route` instead of `routes` would otherwise leave the check empty.
+    assert_invalid_check(
+        r#"type = "routes"
+        route = [{ destination = "0.0.0.0/0" }]"#,
+    );
+}
+
+#[test]
+fn test_sriov_vfs_restricted_to_dpu() {
+    let config = |target: &str| {
+        format!(
+            r#"
+            [system]
+            deployment = "bootc"
+            arch = "aarch64"
+            target = "{}"
+
+            [[required.checks]]
+            type = "sriov_vfs"
+            interface = "ens1f0"
+            count = 8
+            macs = ["02:00:00:00:00:01", "02:00:00"]
+        "#,
+            target
+        )
+    };
+
+    let dpu: Config = config("dpu").parse().expect("DPU config should be valid");
+    match &dpu.required.checks[..] {
+        [Check::SriovVfs {
+            interface,
+            count,
+            macs,
+        }] => {
+            assert_eq!(interface, "ens1f0");
+            assert_eq!(*count, 8);
+            assert_eq!(macs.len(), 2);
+        }
+        other => panic!("unexpected checks: {:?}", other),
+    }
+
+    let edge = config("edge").parse::<Config>();
+    assert!(
+        matches!(edge, Err(GreenlightError::InvalidConfig(_))),
+        "sriov_vfs should be rejected on edge targets, got {:?}",
+        edge
+    );
+}
+
+#[test]
+fn test_parse_mount_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "mount"
+        path = "/var"
+        fstype = "xfs"
+        options = ["rw", "nosuid"]
+
+        [[required.checks]]
+        type = "mount"
+        path = "/sysroot"
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::Mount {
+                path: PathBuf::from("/var"),
+                fstype: Some("xfs".to_string()),
+                options: vec!["rw".to_string(), "nosuid".to_string()],
+                source: None,
+            },
+            Check::Mount {
+                path: PathBuf::from("/sysroot"),
+                fstype: None,
+                options: vec![],
+                source: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_path_writable_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "path_writable"
+        path = "/usr"
+        writable = false
+
+        [[required.checks]]
+        type = "path_writable"
+        path = "/var"
+        writable = true
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::PathWritable {
+                path: PathBuf::from("/usr"),
+                writable: false,
+            },
+            Check::PathWritable {
+                path: PathBuf::from("/var"),
+                writable: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_disk_space_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "disk_space"
+        path = "/var"
+        min_free_bytes = 1073741824
+        min_free_inodes = 10000
+
+        [[required.checks]]
+        type = "disk_space"
+        path = "/sysroot"
+        min_free_percent = 10
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::DiskSpace {
+                path: PathBuf::from("/var"),
+                min_free_bytes: Some(1073741824),
+                min_free_percent: None,
+                min_free_inodes: Some(10000),
+            },
+            Check::DiskSpace {
+                path: PathBuf::from("/sysroot"),
+                min_free_bytes: None,
+                min_free_percent: Some(10),
+                min_free_inodes: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_disk_space_requires_valid_threshold() {
+    for thresholds in ["", "min_free_percent = 250"] {
+        let toml = format!(
+            r#"
+            [system]
+            deployment = "bootc"
+            arch = "x86"
+
+            [[required.checks]]
+            type = "disk_space"
+            path = "/var"
+            {}
+        "#,
+            thresholds
+        );
+
+        assert!(matches!(
+            toml.parse::<Config>(),
+            Err(GreenlightError::InvalidConfig(_))
+        ));
+    }
+}
+
+#[test]
+fn test_parse_kernel_cmdline_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "kernel_cmdline"
+        present = ["nohz_full", "console=ttyS0,115200n8"]
+        absent = ["nosmt"]
+        values = { "isolcpus" = "2-7" }
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::KernelCmdline {
+            present: vec![
+                "nohz_full".to_string(),
+                "console=ttyS0,115200n8".to_string()
+            ],
+            absent: vec!["nosmt".to_string()],
+            values: BTreeMap::from([("isolcpus".to_string(), "2-7".to_string())]),
+        }]
+    );
+}
+
+#[test]
+fn test_kernel_cmdline_check_requires_expectation() {
+    assert_invalid_check(r#"type = "kernel_cmdline""#);
+}
+
+#[test]
+fn test_parse_kernel_version_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "kernel_version"
+        version = ">=5.14, <6"
+        release = '\.el9(_\d+)?\.'
+        match_deployment = true
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::KernelVersion {
+            version: Some(">=5.14, <6".parse().unwrap()),
+            release: Some(r"\.el9(_\d+)?\.".parse().unwrap()),
+            match_deployment: true,
+        }]
+    );
+    assert_invalid_check(r#"type = "kernel_version""#);
+}
+
+#[test]
+fn test_parse_kernel_version_invalid_regex() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "kernel_version"
+        release = "el9("
+    "#;
+
+    assert!(toml::from_str::<Config>(toml).is_err());
+}
+
+#[test]
+fn test_parse_kernel_modules_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "aarch64"
+        target = "dpu"
+
+        [[required.checks]]
+        type = "kernel_modules"
+        loaded = ["mlx5_core"]
+        absent = ["nouveau"]
+        parameters = { "mlx5_core.prof_sel" = "2" }
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::KernelModules {
+            loaded: vec!["mlx5_core".to_string()],
+            absent: vec!["nouveau".to_string()],
+            parameters: BTreeMap::from([("mlx5_core.prof_sel".to_string(), "2".to_string())]),
+        }]
+    );
+}
+
+#[test]
+fn test_kernel_modules_check_requires_expectation() {
+    // `load` instead of `loaded` would otherwise leave the check empty.
+    assert_invalid_check(
+        r#"type = "kernel_modules"
+        load = ["vfio_pci"]"#,
+    );
+}
+
+#[test]
+fn test_parse_sysctl_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "sysctl"
+        key = "net.ipv4.ip_forward"
+        expected = 1
+
+        [[required.checks]]
+        type = "sysctl"
+        key = "kernel.panic"
+        expected = ">= 10"
+
+        [[required.checks]]
+        type = "sysctl"
+        key = "net.ipv4.ip_local_port_range"
+        expected = "32768 60999"
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::Sysctl {
+                key: "net.ipv4.ip_forward".to_string(),
+                expected: "1".parse().unwrap(),
+            },
+            Check::Sysctl {
+                key: "kernel.panic".to_string(),
+                expected: ">=10".parse().unwrap(),
+            },
+            Check::Sysctl {
+                key: "net.ipv4.ip_local_port_range".to_string(),
+                expected: "32768 60999".parse().unwrap(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_selinux_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "selinux"
+        mode = "enforcing"
+        policy = "targeted"
+        avc_domains = ["kubelet_t", "container_t"]
+
+        [[wanted.checks]]
+        type = "selinux"
+        mode = "permissive"
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::Selinux {
+            mode: SelinuxMode::Enforcing,
+            policy: Some("targeted".to_string()),
+            avc_domains: vec!["kubelet_t".to_string(), "container_t".to_string()],
+        }]
+    );
+    assert_eq!(
+        config.wanted.checks,
+        vec![Check::Selinux {
+            mode: SelinuxMode::Permissive,
+            policy: None,
+            avc_domains: vec![],
+        }]
+    );
+}
+
+#[test]
+fn test_parse_file_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "file"
+        path = "/etc/ssh/sshd_config"
+        mode = "0600"
+        owner = "root"
+        group = "0"
+        sha256 = "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03"
+        follow_symlinks = false
+
+        [[required.checks]]
+        type = "file"
+        path = "/etc/ssh/sshd_config.d/50-insecure.conf"
+        exists = false
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::File {
+                path: PathBuf::from("/etc/ssh/sshd_config"),
+                exists: true,
+                mode: Some("0600".parse().unwrap()),
+                owner: Some("root".to_string()),
+                group: Some("0".to_string()),
+                sha256: Some(
+                    "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03"
+                        .parse()
+                        .unwrap()
+                ),
+                follow_symlinks: false,
+            },
+            Check::File {
+                path: PathBuf::from("/etc/ssh/sshd_config.d/50-insecure.conf"),
+                exists: false,
+                mode: None,
+                owner: None,
+                group: None,
+                sha256: None,
+                follow_symlinks: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_file_contains_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "file_contains"
+        path = "/etc/ssh/sshd_config"
+        pattern = "PermitRootLogin no"
+
+        [[required.checks]]
+        type = "file_contains"
+        path = "/etc/ssh/sshd_config"
+        pattern = '^PasswordAuthentication\s+yes'
+        regex = true
+        negate = true
+    "#;
+
+    let config: Config = toml.parse().expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::FileContains {
+                path: PathBuf::from("/etc/ssh/sshd_config"),
+                pattern: ContentPattern::Line("PermitRootLogin no".to_string()),
+                negate: false,
+            },
+            Check::FileContains {
+                path: PathBuf::from("/etc/ssh/sshd_config"),
+                pattern: ContentPattern::Regex(r"^PasswordAuthentication\s+yes".parse().unwrap()),
+                negate: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_file_contains_rejects_invalid_regex() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "file_contains"
+        path = "/etc/ssh/sshd_config"
+        pattern = "PermitRootLogin ("
+        regex = true
+    "#;
+
+    assert!(matches!(
+        toml.parse::<Config>(),
+        Err(GreenlightError::ConfigParse(_))
+    ));
+}
+
+#[test]
+fn test_parse_config_value_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "config_value"
+        path = "/etc/microshift/config.yaml"
+        format = "yaml"
+        key = "dns.baseDomain"
+        expected = "example.com"
+
+        [[required.checks]]
+        type = "config_value"
+        path = "/etc/myapp/config.toml"
+        format = "toml"
+        key = "server.port"
+        expected = 8080
+
+        [[required.checks]]
+        type = "config_value"
+        path = "/etc/myapp/settings.ini"
+        format = "ini"
+        key = "server.tls"
+        expected = true
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::ConfigValue {
+                path: PathBuf::from("/etc/microshift/config.yaml"),
+                format: ConfigFormat::Yaml,
+                key: "dns.baseDomain".to_string(),
+                expected: ConfigScalar::String("example.com".to_string()),
+            },
+            Check::ConfigValue {
+                path: PathBuf::from("/etc/myapp/config.toml"),
+                format: ConfigFormat::Toml,
+                key: "server.port".to_string(),
+                expected: ConfigScalar::Integer(8080),
+            },
+            Check::ConfigValue {
+                path: PathBuf::from("/etc/myapp/settings.ini"),
+                format: ConfigFormat::Ini,
+                key: "server.tls".to_string(),
+                expected: ConfigScalar::Bool(true),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_process_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "process"
+        name = "ovs-vswitchd"
+        max_count = 1
+        user = "root"
+
+        [[required.checks]]
+        type = "process"
+        cmdline_regex = 'conmon .*--name my-app'
+        max_count = 0
+
+        [[required.checks]]
+        type = "process"
+        name = "worker"
+        min_count = 2
+    "#;
+
+    let config: Config = toml.parse().expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![
+            Check::Process {
+                name: Some("ovs-vswitchd".to_string()),
+                cmdline_regex: None,
+                min_count: None,
+                max_count: Some(1),
+                user: Some("root".to_string()),
+            },
+            Check::Process {
+                name: None,
+                cmdline_regex: Some("conmon .*--name my-app".parse().unwrap()),
+                min_count: None,
+                max_count: Some(0),
+                user: None,
+            },
+            Check::Process {
+                name: Some("worker".to_string()),
+                cmdline_regex: None,
+                min_count: Some(2),
+                max_count: None,
+                user: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_process_check_requires_selector() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "process"
+        user = "root"
+    "#;
+
+    assert!(matches!(
+        toml.parse::<Config>(),
+        Err(GreenlightError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_parse_memory_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "aarch64"
+        target = "dpu"
+
+        [[required.checks]]
+        type = "memory"
+        min_available_mb = 2048
+        max_swap_used_mb = 0
+        hugepages = { size = "1G", count = 8 }
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::Memory {
+            min_available_mb: Some(2048),
+            max_swap_used_mb: Some(0),
+            hugepages: Some(HugePages {
+                size: "1G".parse().unwrap(),
+                count: 8,
+            }),
+        }]
+    );
+}
+
+#[test]
+fn test_memory_check_requires_expectation() {
+    assert_invalid_check(r#"type = "memory""#);
+}
+
+#[test]
+fn test_parse_cpu_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "cpu"
+        online = "0-7"
+        isolated = "2-7"
+        min_count = 8
+        flags = ["avx2"]
+
+        [[wanted.checks]]
+        type = "cpu"
+        isolated = ""
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::Cpu {
+            online: Some("0-7".parse().unwrap()),
+            isolated: Some("2-7".parse().unwrap()),
+            min_count: Some(8),
+            flags: vec!["avx2".to_string()],
+        }]
+    );
+    assert_eq!(
+        config.wanted.checks,
+        vec![Check::Cpu {
+            online: None,
+            isolated: Some("".parse().unwrap()),
+            min_count: None,
+            flags: vec![],
+        }]
+    );
+}
+
+#[test]
+fn test_cpu_check_requires_expectation() {
+    assert_invalid_check(r#"type = "cpu""#);
+}
+
+#[test]
+fn test_parse_swap_check() {
+    let toml = r#"
+        [system]
+        deployment = "bootc"
+        arch = "x86"
+
+        [[required.checks]]
+        type = "swap"
+        allowed = ["zram"]
+        max_total_mb = 4096
+
+        [[wanted.checks]]
+        type = "swap"
+        disabled = true
+    "#;
+
+    let config: Config = toml::from_str(toml).expect("Failed to parse TOML");
+    assert_eq!(
+        config.required.checks,
+        vec![Check::Swap {
+            allowed: Some(vec![SwapKind::Zram]),
+            max_total_mb: Some(4096),
+            disabled: false,
+        }]
+    );
+    assert_eq!(
+        config.wanted.checks,
+        vec![Check::Swap {
+            allowed: None,
+            max_total_mb: None,
+            disabled: true,
+        }]
+    );
+}
+
+#[test]
+fn test_swap_check_rejects_empty_or_conflicting_settings() {
+    assert_invalid_check(r#"type = "swap""#);
+    // `disable` instead of `disabled` would otherwise leave the check empty.
+    assert_invalid_check(
+        r#"type = "swap"
+        disable = true"#,
+    );
+    assert_invalid_check(
+        r#"type = "swap"
+        disabled = true
+        max_total_mb = 0"#,
+    );
+}